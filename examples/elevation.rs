@@ -49,6 +49,15 @@ fn App() -> Element {
                         shape: Shape::Medium,
                         elevation,
                     }
+
+                    Surface {
+                        width: "92",
+                        height: "92",
+                        shape: Shape::Medium,
+                        elevation,
+                        elevation_mode: ElevationMode::TonalWithShadow,
+                        interactive: true,
+                    }
                 }
             }
         }
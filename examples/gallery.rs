@@ -16,9 +16,6 @@ fn main() {
 
 #[component]
 fn App() -> Element {
-    let theme = use_material_theme();
-    let theme = theme.read();
-
     let mut selected = use_signal(bool::default);
     let mut current_value = use_signal(|| 9);
 
@@ -27,8 +24,8 @@ fn App() -> Element {
             direction: "vertical",
             padding: "12",
             spacing: "24",
-            background: "{theme.surface_container_highest}",
-            color: "{theme.on_surface}",
+            background: ColorRole::SurfaceContainerHighest,
+            color: ColorRole::OnSurface,
             width: "fill",
             height: "fill",
 
@@ -237,7 +234,7 @@ fn App() -> Element {
                             }
 
                             Surface {
-                                background: "{theme.primary}",
+                                background: ColorRole::Primary,
                                 width: "92",
                                 height: "92",
                                 shape: Shape::Medium,
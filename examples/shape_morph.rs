@@ -0,0 +1,58 @@
+use freya::prelude::*;
+use freya_material::prelude::*;
+
+fn main() {
+    launch_cfg(
+        App,
+        LaunchConfig::<()>::new()
+            .with_title("Shape Morph")
+            .with_size(300., 300.)
+            .with_roboto(),
+    );
+}
+
+const SHAPES: [MorphShape; 4] = [
+    MorphShape::Circle,
+    MorphShape::Star {
+        points: 8,
+        inner_radius: 0.8,
+        rounding: 0.15,
+    },
+    MorphShape::Polygon {
+        sides: 5,
+        rounding: 0.2,
+    },
+    MorphShape::Corners(CornerShape::all(24.0)),
+];
+
+#[component]
+fn App() -> Element {
+    let theme = use_material_theme();
+    let theme = theme.read();
+
+    let mut index = use_signal(|| 0);
+    let morph = use_shape_morph(SHAPES[index()]);
+
+    rsx! {
+        Surface {
+            main_align: "center",
+            cross_align: "center",
+            background: "{theme.surface}",
+            width: "fill",
+            height: "fill",
+
+            Surface {
+                background: ColorRole::PrimaryContainer,
+                width: "160",
+                height: "160",
+                morph,
+                on_click: move |_| index.set((index() + 1) % SHAPES.len()),
+
+                StateLayer {
+                    color: ColorRole::OnPrimaryContainer,
+                    morph,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use freya::prelude::{
+    component, dioxus_core, dioxus_elements, fc_to_builder, launch_cfg, rsx, Element, GlobalSignal,
+    IntoDynNode, LaunchConfig, Readable, Writable,
+};
+use freya_material::prelude::*;
+use material_colors::color::Argb;
+
+fn main() {
+    launch_cfg(
+        App,
+        LaunchConfig::<()>::new()
+            .with_title("Material Design: Theme")
+            .with_size(200., 200.)
+            .with_roboto(),
+    );
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        MaterialTheme {
+            seed: Argb::from_u32(0xFF6750A4),
+            variant: SchemeVariant::TonalSpot,
+
+            ThemeDemo {}
+        }
+    }
+}
+
+#[component]
+fn ThemeDemo() -> Element {
+    let mut context = use_material_context();
+    let theme = use_material_theme();
+    let theme = theme.read();
+
+    let mode = context.read().mode;
+
+    rsx! {
+        Surface {
+            direction: "vertical",
+            padding: "12",
+            spacing: "24",
+            background: "{theme.surface_container_highest}",
+            color: "{theme.on_surface}",
+            width: "fill",
+            height: "fill",
+
+            Typography {
+                variant: TypescaleVariant::Title,
+
+                "Current mode: {mode:?}"
+            }
+
+            rect {
+                direction: "horizontal",
+                spacing: "8",
+
+                for mode in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+                    Button {
+                        label: format!("{mode:?}"),
+                        on_click: move |_| context.write().mode = mode,
+                        style: ButtonStyle::FilledTonal,
+                    }
+                }
+            }
+
+            rect {
+                direction: "horizontal",
+                spacing: "8",
+
+                for variant in [SchemeVariant::TonalSpot, SchemeVariant::Vibrant, SchemeVariant::Expressive] {
+                    Button {
+                        label: format!("{variant:?}"),
+                        on_click: move |_| {
+                            let seed = context.read().seed;
+
+                            context.write().set_seed(seed, variant);
+                        },
+                        style: ButtonStyle::Outlined,
+                    }
+                }
+            }
+
+            rect {
+                direction: "horizontal",
+                spacing: "8",
+
+                for contrast in [Contrast::Standard, Contrast::Medium, Contrast::High] {
+                    Button {
+                        label: format!("{contrast:?}"),
+                        on_click: move |_| context.write().set_contrast(contrast),
+                        style: ButtonStyle::Text,
+                    }
+                }
+            }
+
+            MaterialTheme {
+                inverse: true,
+
+                Surface {
+                    padding: "16",
+                    shape: Shape::Medium,
+                    background: ColorRole::SurfaceContainer,
+                    color: ColorRole::OnSurface,
+
+                    Typography {
+                        variant: TypescaleVariant::Body,
+
+                        "This card uses the inverse scheme."
+                    }
+                }
+            }
+        }
+    }
+}
@@ -55,6 +55,24 @@ fn App() -> Element {
                     }
                 }
             }
+
+            Typography {
+                selectable: true,
+                spans: vec![
+                    TextSpan::new("Rich text can mix "),
+                    TextSpan {
+                        prominent: Some(true),
+                        color: Some(ColorRole::Primary.into()),
+                        ..TextSpan::new("emphasized")
+                    },
+                    TextSpan::new(" and "),
+                    TextSpan {
+                        variant: Some(TypescaleVariant::Title),
+                        ..TextSpan::new("larger")
+                    },
+                    TextSpan::new(" spans in one paragraph."),
+                ],
+            }
         }
     }
 }
@@ -15,23 +15,35 @@ pub fn Button(
     style: ButtonStyle,
     icon: Option<IconData>,
     label: String,
+    /// Name of an [`ExtendedColor`] used instead of the primary color.
+    extended_color: Option<String>,
+    /// Defaults to [`Shape::Full`].
+    #[props(into, default = Shape::Full.into())]
+    shape: ShapeValue,
     on_click: EventHandler<MouseEvent>,
     #[props(default)] disabled: bool,
 ) -> Element {
+    let context = use_material_context();
+    let is_dark = use_material_is_dark();
     let theme = use_material_theme();
     let theme = theme.read();
 
+    let (accent, tonal) = extended_color
+        .and_then(|name| context.read().extended_color(&name, is_dark()))
+        .map_or_else(
+            || (ColorGroup::primary(&theme), ColorGroup::secondary(&theme)),
+            |group| (group, group),
+        );
+
     let (background, color, border) = match (style, disabled) {
-        (ButtonStyle::Elevated, false) => (Some(theme.surface_container_low), theme.primary, None),
-        (ButtonStyle::Filled, false) => (Some(theme.primary), theme.on_primary, None),
-        (ButtonStyle::FilledTonal, false) => (
-            Some(theme.secondary_container),
-            theme.on_secondary_container,
-            None,
-        ),
+        (ButtonStyle::Elevated, false) => (Some(theme.surface_container_low), accent.color, None),
+        (ButtonStyle::Filled, false) => (Some(accent.color), accent.on_color, None),
+        (ButtonStyle::FilledTonal, false) => {
+            (Some(tonal.color_container), tonal.on_color_container, None)
+        }
         (ButtonStyle::Outlined, false) => (
             None,
-            theme.primary,
+            accent.color,
             Some(format!("1 inner {}", theme.outline)),
         ),
         (ButtonStyle::Outlined, true) => (
@@ -42,7 +54,7 @@ pub fn Button(
                 theme.on_surface.with_alpha_f32(0.12).as_rgba()
             )),
         ),
-        (ButtonStyle::Text, false) => (None, theme.primary, None),
+        (ButtonStyle::Text, false) => (None, accent.color, None),
         (ButtonStyle::Text, true) => (None, theme.on_surface.with_alpha_f32(0.38), None),
         (ButtonStyle::Elevated | ButtonStyle::Filled | ButtonStyle::FilledTonal, true) => (
             Some(theme.on_surface.with_alpha_f32(0.12)),
@@ -51,7 +63,7 @@ pub fn Button(
         ),
     };
 
-    let (background, color) = (background.map(|color| color.as_rgba()), color.as_rgba());
+    let (background, color) = (background.map(ColorValue::from), color.as_rgba());
 
     let padding = if style == ButtonStyle::Text { 12 } else { 24 };
 
@@ -64,39 +76,34 @@ pub fn Button(
     let (reference, size) = use_node();
 
     rsx! {
-        rect {
+        Surface {
             direction: "horizontal",
             cross_align: "center",
-            height: "40",
+            min_height: "40",
             padding: "0 {padding} 0 {padding_left}",
-            corner_radius: "20",
+            shape,
             spacing: "8",
             background,
             color: color.as_str(),
             border,
-            shadow: if style == ButtonStyle::Elevated && !disabled {
-                Some(
-                    Elevation::Level1
-                        .as_shadows()
-                        .map(|value| format!("{} {} {} {} {}", value.x, value.y, value.blur, value.spread, value.fill))
-                        .join(", ")
-                )
+            elevation: if style == ButtonStyle::Elevated && !disabled {
+                Some(Elevation::Level1)
             } else {
                 None
             },
+            interactive: true,
             overflow: "clip",
-
             reference,
-
-            onclick: move |data| if !disabled {
+            on_click: move |data| if !disabled {
                 on_click.call(data);
             },
 
             if !disabled {
                 StateLayer {
                     color: color.as_str(),
+                    shape,
                     width: "{size.area.size.width}",
-                    height: "40",
+                    height: "{size.area.size.height}",
                     position_left: "-{padding_left}"
                 }
             }
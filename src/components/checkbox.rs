@@ -1,6 +1,6 @@
-use crate::prelude::*;
+use crate::{material_design::motion::Duration, prelude::*};
 use freya::prelude::*;
-use freya_motion::{use_transition, Curve};
+use freya_motion::use_transition;
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CheckboxState {
@@ -11,20 +11,19 @@ pub enum CheckboxState {
 }
 
 impl CheckboxState {
-    const fn into_curve(self) -> Curve {
+    const fn into_motion(self, scheme: MotionScheme) -> Motion {
         if self.into_bool() {
-            Easing::EmphasizedAccelerate
+            scheme.motion(Easing::EmphasizedAccelerate, Duration::Short3)
         } else {
-            Easing::EmphasizedDecelerate
+            scheme.motion(Easing::EmphasizedDecelerate, Duration::Medium3)
         }
-        .into_value()
     }
 
-    const fn into_duration(self) -> u64 {
+    const fn into_spring(self, scheme: MotionScheme) -> Spring {
         if self.into_bool() {
-            150
+            scheme.spatial_spring(SpringSpeed::Fast)
         } else {
-            350
+            scheme.spatial_spring(SpringSpeed::Default)
         }
     }
 
@@ -95,17 +94,29 @@ impl CheckboxState {
 pub fn Checkbox(
     state: CheckboxState,
     on_click: EventHandler<MouseEvent>,
+    /// Name of an [`ExtendedColor`] used instead of the primary color.
+    extended_color: Option<String>,
     #[props(default)] error: bool,
     #[props(default)] disabled: bool,
 ) -> Element {
+    let context = use_material_context();
+    let is_dark = use_material_is_dark();
     let theme = use_material_theme();
     let theme = theme.read();
 
     let mut is_previous_unchecked = use_signal(bool::default);
 
+    let accent = if error {
+        ColorGroup::error(&theme)
+    } else {
+        extended_color
+            .and_then(|name| context.read().extended_color(&name, is_dark()))
+            .unwrap_or_else(|| ColorGroup::primary(&theme))
+    };
+
     let [background, color, border_color] = match [error, disabled] {
-        [false, false] => [theme.primary, theme.on_primary, theme.on_surface_variant],
-        [true, false] => [theme.error, theme.on_error, theme.error],
+        [false, false] => [accent.color, accent.on_color, theme.on_surface_variant],
+        [true, false] => [accent.color, accent.on_color, accent.color],
         [_, true] => [
             theme.on_surface.with_alpha_f32(0.38),
             theme.surface,
@@ -117,10 +128,8 @@ pub fn Checkbox(
         ],
     };
 
-    let state_layer_color = if error {
-        theme.error
-    } else if state.into_bool() {
-        theme.primary
+    let state_layer_color = if error || state.into_bool() {
+        accent.color
     } else {
         theme.on_surface
     };
@@ -131,63 +140,111 @@ pub fn Checkbox(
         border_color.as_rgba(),
     ];
 
+    let motion_scheme = use_hook(|| context.peek().motion_scheme);
+    let motion = state.into_motion(motion_scheme);
+    let fade = motion_scheme.motion(Easing::Linear, Duration::Short1);
+    let spring = state.into_spring(motion_scheme);
+    let fade_spring = motion_scheme.effects_spring(SpringSpeed::Fast);
+
     let rect_transition = use_transition(move |context| {
         context.add_tween(
             "radius",
             state.into_radius(),
-            state.into_curve(),
-            state.into_duration(),
+            motion.curve,
+            motion.as_millis(),
+        );
+        context.add_tween(
+            "opacity",
+            state.into_opacity(),
+            fade.curve,
+            fade.as_millis(),
         );
-        context.add_tween("opacity", state.into_opacity(), Curve::LINEAR, 50);
     });
 
-    let [radius, opacity] = [
-        rect_transition.get::<f32>("radius"),
-        rect_transition.get::<f32>("opacity"),
-    ];
-
     let checkmark_transition = use_transition(move |context| {
         context.add_tween(
             "rotation",
             state.into_rotation(),
-            state.into_curve(),
-            state.into_duration(),
+            motion.curve,
+            motion.as_millis(),
         );
 
         context.add_tween(
             "offset",
             state.into_offset(),
-            state.into_curve(),
-            state.into_duration(),
+            motion.curve,
+            motion.as_millis(),
         );
 
         context.add_tween(
             "width",
             state.into_width(),
-            state.into_curve(),
-            state.into_duration(),
+            motion.curve,
+            motion.as_millis(),
         );
 
         context.add_tween(
             "height",
             state.into_height(),
-            state.into_curve(),
-            state.into_duration(),
+            motion.curve,
+            motion.as_millis(),
         );
     });
 
-    let ([rotation, width, height], offset): ([f32; 3], _) = (
-        [
-            checkmark_transition.get("rotation"),
-            checkmark_transition.get("width"),
-            checkmark_transition.get("height"),
-        ],
-        checkmark_transition.get::<Point2D>("offset"),
-    );
+    let mut rect_springs = use_springs(move |context| {
+        context.add_spring("radius", state.into_radius(), spring);
+        context.add_spring("opacity", state.into_opacity(), fade_spring);
+    });
+
+    let mut checkmark_springs = use_springs(move |context| {
+        context.add_spring("rotation", state.into_rotation(), spring);
+        context.add_spring("offset", state.into_offset(), spring);
+        context.add_spring("width", state.into_width(), spring);
+        context.add_spring("height", state.into_height(), spring);
+    });
+
+    let ([radius, opacity, rotation, width, height], offset): ([f32; 5], Point2D) =
+        if motion_scheme.uses_springs() {
+            (
+                [
+                    rect_springs.get("radius"),
+                    rect_springs.get("opacity"),
+                    checkmark_springs.get("rotation"),
+                    checkmark_springs.get("width"),
+                    checkmark_springs.get("height"),
+                ],
+                checkmark_springs.get("offset"),
+            )
+        } else {
+            (
+                [
+                    rect_transition.get("radius"),
+                    rect_transition.get("opacity"),
+                    checkmark_transition.get("rotation"),
+                    checkmark_transition.get("width"),
+                    checkmark_transition.get("height"),
+                ],
+                checkmark_transition.get("offset"),
+            )
+        };
 
     use_effect(use_reactive!(|state| {
+        let uses_springs = motion_scheme.uses_springs();
+
         match state {
             CheckboxState::Unchecked => {}
+            CheckboxState::Intermediate | CheckboxState::Checked if uses_springs => {
+                if *is_previous_unchecked.peek() && state == CheckboxState::Checked {
+                    checkmark_springs.forced_set("width", 0.0);
+                }
+
+                checkmark_springs.set("rotation", state.into_rotation());
+                checkmark_springs.set("offset", state.into_offset());
+                checkmark_springs.set("width", state.into_width());
+                checkmark_springs.set("height", state.into_height());
+
+                checkmark_springs.play_all();
+            }
             CheckboxState::Intermediate | CheckboxState::Checked => {
                 if *is_previous_unchecked.peek() && state == CheckboxState::Checked {
                     checkmark_transition.forced_set("width", 0.0);
@@ -202,10 +259,17 @@ pub fn Checkbox(
             }
         }
 
-        rect_transition.play([
-            ("radius", state.into_radius()),
-            ("opacity", state.into_opacity()),
-        ]);
+        if uses_springs {
+            rect_springs.play([
+                ("radius", state.into_radius()),
+                ("opacity", state.into_opacity()),
+            ]);
+        } else {
+            rect_transition.play([
+                ("radius", state.into_radius()),
+                ("opacity", state.into_opacity()),
+            ]);
+        }
 
         is_previous_unchecked.set(state == CheckboxState::Unchecked);
     }));
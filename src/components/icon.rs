@@ -1,6 +1,8 @@
 use freya::prelude::*;
 pub use material_icons::IconStyle;
 
+use crate::{material_design::ColorValue, use_material_theme};
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IconData {
     pub name: &'static str,
@@ -21,12 +23,14 @@ impl Default for IconData {
 #[component]
 pub fn Icon(
     name: String,
-    color: Option<String>,
+    #[props(into)] color: Option<ColorValue>,
     width: Option<String>,
     height: Option<String>,
     #[props(default = IconStyle::Outlined)] style: IconStyle,
     #[props(default)] filled: bool,
 ) -> Element {
+    let theme = use_material_theme();
+    let color = color.map(|value| value.resolve(&theme.read()));
     let svg_data = static_bytes(material_icons::icon(&name, style, filled));
 
     rsx! {
@@ -14,6 +14,9 @@ pub fn IconButton(
     style: IconButtonStyle,
     icon: IconData,
     selected: Option<bool>,
+    /// Defaults to [`Shape::Full`].
+    #[props(into, default = Shape::Full.into())]
+    shape: ShapeValue,
     on_click: EventHandler<MouseEvent>,
     #[props(default)] disabled: bool,
 ) -> Element {
@@ -65,28 +68,28 @@ pub fn IconButton(
         ),
     };
 
-    let (background, color) = (background.map(|color| color.as_rgba()), color.as_rgba());
+    let (background, color) = (background.map(ColorValue::from), color.as_rgba());
 
     rsx! {
-        rect {
+        Surface {
             direction: "horizontal",
             cross_align: "center",
             height: "40",
             width: "40",
             padding: "8",
-            corner_radius: "20",
+            shape,
             background,
             color: color.as_str(),
             border,
             overflow: "clip",
-
-            onclick: move |data| if !disabled {
+            on_click: move |data| if !disabled {
                 on_click.call(data);
             },
 
             if !disabled {
                 StateLayer {
                     color: color.as_str(),
+                    shape,
                     width: "40",
                     height: "40",
                     position_left: "-8",
@@ -0,0 +1,106 @@
+use crate::{
+    prelude::*,
+    theme::{use_parent_material_context, use_provide_material_theme},
+};
+use freya::prelude::*;
+use material_colors::color::Argb;
+
+type Overrides = (
+    Option<Argb>,
+    Option<SchemeVariant>,
+    Option<Contrast>,
+    Option<ThemeMode>,
+    bool,
+);
+
+fn derive_theme(
+    parent: &MaterialTheme,
+    parent_is_dark: bool,
+    (seed, variant, contrast, mode, inverse): Overrides,
+) -> MaterialTheme {
+    let mut theme = parent.clone();
+
+    if seed.is_some() || variant.is_some() || contrast.is_some() {
+        let seed = seed.unwrap_or(theme.seed);
+        let variant = variant.unwrap_or(theme.variant);
+
+        if (theme.seed, theme.variant) != (seed, variant) {
+            theme.imported_schemes.clear();
+        }
+
+        theme.seed = seed;
+        theme.variant = variant;
+        theme.contrast = contrast.unwrap_or(theme.contrast);
+        theme.regenerate();
+    }
+
+    theme.mode = match (inverse, parent_is_dark) {
+        (true, true) => ThemeMode::Light,
+        (true, false) => ThemeMode::Dark,
+        (false, _) => mode.unwrap_or(theme.mode),
+    };
+
+    theme
+}
+
+fn derive_root_theme(overrides: Overrides) -> MaterialTheme {
+    let theme = MaterialTheme::default();
+    let is_dark = theme.mode == ThemeMode::Dark;
+
+    derive_theme(&theme, is_dark, overrides)
+}
+
+/// Provides a theme derived from the nearest parent theme to its children.
+///
+/// Without a parent, the theme is derived from [`MaterialTheme::default`], so
+/// a provider at the root of the app sets the seed color the whole app is
+/// generated from:
+///
+/// ```rust,ignore
+/// rsx! {
+///     MaterialTheme {
+///         seed: Argb::from_u32(0xFF6750A4),
+///         variant: SchemeVariant::TonalSpot,
+///
+///         Content {}
+///     }
+/// }
+/// ```
+#[component]
+pub fn MaterialTheme(
+    seed: Option<Argb>,
+    variant: Option<SchemeVariant>,
+    contrast: Option<Contrast>,
+    mode: Option<ThemeMode>,
+    /// Uses the light scheme under a dark parent and vice versa.
+    #[props(default)]
+    inverse: bool,
+    children: Element,
+) -> Element {
+    let parent = use_parent_material_context();
+
+    let mut theme = use_provide_material_theme(|| match parent {
+        Some(parent) => derive_theme(
+            &parent.theme.peek(),
+            *parent.is_dark.peek(),
+            (seed, variant, contrast, mode, inverse),
+        ),
+        None => derive_root_theme((seed, variant, contrast, mode, inverse)),
+    })
+    .theme;
+
+    use_effect(use_reactive!(|(seed, variant, contrast, mode, inverse)| {
+        theme.set(match parent {
+            Some(parent) => derive_theme(
+                &parent.theme.read(),
+                (parent.is_dark)(),
+                (seed, variant, contrast, mode, inverse),
+            ),
+            None => derive_root_theme((seed, variant, contrast, mode, inverse)),
+        });
+    }));
+
+    rsx! {
+        {children}
+    }
+}
@@ -2,7 +2,9 @@ mod button;
 mod checkbox;
 mod icon;
 mod icon_button;
+mod material_theme;
 mod radio_button;
+mod spring;
 mod state_layer;
 mod surface;
 mod typography;
@@ -12,8 +14,10 @@ pub use self::{
     checkbox::{Checkbox, CheckboxState},
     icon::{Icon, IconData, IconStyle},
     icon_button::{IconButton, IconButtonStyle},
+    material_theme::MaterialTheme,
     radio_button::RadioButton,
+    spring::{use_springs, SpringValue, SpringsContext, UseSprings},
     state_layer::StateLayer,
-    surface::Surface,
-    typography::Typography,
+    surface::{use_animated_elevation, use_shape_morph, Surface},
+    typography::{TextSpan, Typography},
 };
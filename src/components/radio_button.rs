@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{material_design::motion::Duration, prelude::*};
 use freya::prelude::*;
 use freya_motion::use_transition;
 
@@ -27,22 +27,31 @@ pub fn RadioButton(
     let onpointerenter = move |_| hovered.set(true);
     let onpointerleave = move |_| hovered.set(false);
 
+    let context = use_material_context();
+    let motion_scheme = use_hook(|| context.peek().motion_scheme);
+    let motion = motion_scheme.motion(Easing::EmphasizedDecelerate, Duration::Medium3);
+    let spring = motion_scheme.spatial_spring(SpringSpeed::Default);
+
     let radius_transition = use_transition(move |context| {
-        context.add_tween(
-            "radius",
-            0.0,
-            Easing::EmphasizedDecelerate.as_value(),
-            EasingDuration::Medium.as_value()[2],
-        );
+        context.add_tween("radius", 0.0, motion.curve, motion.as_millis());
+    });
+    let mut radius_springs = use_springs(move |context| {
+        context.add_spring("radius", 0.0, spring);
     });
 
-    let radius = radius_transition.get::<f32>("radius");
+    let radius: f32 = if motion_scheme.uses_springs() {
+        radius_springs.get("radius")
+    } else {
+        radius_transition.get("radius")
+    };
 
     use_effect(use_reactive!(|selected| {
-        if selected {
-            radius_transition.play([("radius", 8.0)]);
+        let radius = if selected { 8.0 } else { 0.0 };
+
+        if motion_scheme.uses_springs() {
+            radius_springs.play([("radius", radius)]);
         } else {
-            radius_transition.play([("radius", 0.0)]);
+            radius_transition.play([("radius", radius)]);
         }
     }));
 
@@ -0,0 +1,229 @@
+use std::time::Instant;
+
+use crate::prelude::*;
+use freya::prelude::*;
+
+/// Longest time a spring is advanced by in one frame, so a late frame doesn't
+/// make it jump.
+const MAX_STEP: f32 = 1.0 / 30.0;
+const REST_DISPLACEMENT: f32 = 0.005;
+const REST_VELOCITY: f32 = 0.05;
+
+/// A value that can be animated by [`use_springs`], split into channels that
+/// are sprung independently.
+pub trait SpringValue: Copy {
+    fn into_channels(self) -> [f32; 2];
+
+    fn from_channels(channels: [f32; 2]) -> Self;
+}
+
+impl SpringValue for f32 {
+    fn into_channels(self) -> [f32; 2] {
+        [self, 0.0]
+    }
+
+    fn from_channels([value, _]: [f32; 2]) -> Self {
+        value
+    }
+}
+
+impl SpringValue for Point2D {
+    fn into_channels(self) -> [f32; 2] {
+        [self.x, self.y]
+    }
+
+    fn from_channels([x, y]: [f32; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Channel {
+    value: f32,
+    velocity: f32,
+    target: f32,
+}
+
+impl Channel {
+    const fn at(value: f32) -> Self {
+        Self {
+            value,
+            velocity: 0.0,
+            target: value,
+        }
+    }
+
+    /// Advances the channel by `time` seconds, returning whether it came to
+    /// rest.
+    fn advance(&mut self, spring: Spring, time: f32) -> bool {
+        let (displacement, velocity) = spring.solve(self.value - self.target, self.velocity, time);
+
+        if displacement.abs() < REST_DISPLACEMENT && velocity.abs() < REST_VELOCITY {
+            *self = Self::at(self.target);
+
+            true
+        } else {
+            self.value = self.target + displacement;
+            self.velocity = velocity;
+
+            false
+        }
+    }
+}
+
+struct SpringEntry {
+    name: &'static str,
+    spring: Spring,
+    channels: [Channel; 2],
+}
+
+#[derive(Default)]
+pub struct SpringsContext {
+    entries: Vec<SpringEntry>,
+}
+
+impl SpringsContext {
+    pub fn add_spring(&mut self, name: &'static str, value: impl SpringValue, spring: Spring) {
+        self.entries.push(SpringEntry {
+            name,
+            spring,
+            channels: value.into_channels().map(Channel::at),
+        });
+    }
+
+    fn entry_mut(&mut self, name: &str) -> &mut SpringEntry {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.name == name)
+            .unwrap_or_else(|| panic!("no spring named `{name}`"))
+    }
+
+    /// Advances every spring by `time` seconds, returning whether all of them
+    /// came to rest.
+    fn advance(&mut self, time: f32) -> bool {
+        self.entries.iter_mut().fold(true, |resting, entry| {
+            let spring = entry.spring;
+
+            entry.channels.iter_mut().fold(resting, |resting, channel| {
+                channel.advance(spring, time) & resting
+            })
+        })
+    }
+}
+
+/// Values animated by actual springs, created with [`use_springs`].
+///
+/// Unlike tweens, changing the target of a moving spring keeps its velocity,
+/// and underdamped springs oscillate around their target before settling.
+#[derive(Clone, Copy)]
+pub struct UseSprings {
+    context: Signal<SpringsContext>,
+    task: Signal<Option<Task>>,
+    platform: UsePlatform,
+}
+
+impl UseSprings {
+    /// Returns the current value of the spring named `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no spring named `name`.
+    #[must_use]
+    pub fn get<T: SpringValue>(&self, name: &str) -> T {
+        let context = self.context.read();
+        let entry = context
+            .entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .unwrap_or_else(|| panic!("no spring named `{name}`"));
+
+        T::from_channels(entry.channels.map(|channel| channel.value))
+    }
+
+    /// Returns whether any spring is still moving.
+    #[must_use]
+    pub fn is_playing(&self) -> bool {
+        self.task.read().is_some()
+    }
+
+    /// Jumps the spring named `name` to `value` and stops it.
+    pub fn forced_set(&mut self, name: &str, value: impl SpringValue) {
+        self.context.write().entry_mut(name).channels = value.into_channels().map(Channel::at);
+    }
+
+    /// Sets the target of the spring named `name` without starting it.
+    pub fn set(&mut self, name: &str, target: impl SpringValue) {
+        let mut context = self.context.write();
+        let entry = context.entry_mut(name);
+
+        for (channel, target) in entry.channels.iter_mut().zip(target.into_channels()) {
+            channel.target = target;
+        }
+    }
+
+    /// Changes the spring that moves the value named `name`, keeping its
+    /// current velocity.
+    pub fn set_spring(&mut self, name: &str, spring: Spring) {
+        self.context.write().entry_mut(name).spring = spring;
+    }
+
+    /// Moves the given springs towards their new targets.
+    pub fn play<T: SpringValue>(&mut self, targets: impl IntoIterator<Item = (&'static str, T)>) {
+        for (name, target) in targets {
+            self.set(name, target);
+        }
+
+        self.play_all();
+    }
+
+    /// Moves every spring towards its target.
+    pub fn play_all(&mut self) {
+        if self.task.peek().is_some() {
+            return;
+        }
+
+        let (mut context, mut task, platform) = (self.context, self.task, self.platform);
+
+        task.set(Some(spawn(async move {
+            let mut ticker = platform.new_ticker();
+            let mut last = Instant::now();
+
+            loop {
+                platform.request_animation_frame();
+                ticker.tick().await;
+
+                let now = Instant::now();
+                let time = now.duration_since(last).as_secs_f32().min(MAX_STEP);
+
+                last = now;
+
+                if context.write().advance(time) {
+                    break;
+                }
+            }
+
+            task.set(None);
+        })));
+    }
+}
+
+/// Creates a group of values animated by springs, the spring counterpart of
+/// `use_transition` used by components when the [`MotionScheme`] uses
+/// springs.
+pub fn use_springs(init: impl FnOnce(&mut SpringsContext)) -> UseSprings {
+    let platform = use_platform();
+    let context = use_signal(|| {
+        let mut context = SpringsContext::default();
+
+        init(&mut context);
+
+        context
+    });
+    let task = use_signal(|| None);
+
+    UseSprings {
+        context,
+        task,
+        platform,
+    }
+}
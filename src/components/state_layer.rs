@@ -1,6 +1,7 @@
-use crate::prelude::*;
+use crate::{material_design::motion::Duration, prelude::*};
 use freya::prelude::*;
-use freya_motion::{use_transition, Curve};
+use freya_motion::use_transition;
+use skia_safe::{gradient_shader::GradientShaderColors, ClipOp, Paint, Shader, TileMode};
 
 const INITIAL_ORIGIN_SCALE: f32 = 0.2;
 const PADDING: f32 = 10.0;
@@ -85,11 +86,27 @@ impl RippleInfo {
 pub fn StateLayer(
     position_left: Option<String>,
     position_top: Option<String>,
-    color: String,
-    shape: Option<Shape>,
+    #[props(into)] color: ColorValue,
+    #[props(into)] shape: Option<ShapeValue>,
+    /// Outline to clip to instead of `shape`, e.g. from [`use_shape_morph`].
+    morph: Option<ShapeMorph>,
     height: Option<String>,
     width: Option<String>,
 ) -> Element {
+    let context = use_material_context();
+    let theme = use_material_theme();
+    let corners = shape.map(|shape| shape.resolve(&context.read().shape_scale));
+    let outline = morph.or_else(|| {
+        corners
+            .filter(CornerShape::is_cut)
+            .map(|corners| ShapeMorph::fixed(MorphShape::Corners(corners)))
+    });
+    let corner_radius = corners
+        .filter(|_| outline.is_none())
+        .map(|corners| corners.to_string());
+    let canvas_color = color.resolve_color(&theme.read());
+    let color = color.resolve(&theme.read());
+
     let (reference, size) = use_node_signal();
 
     let width = width.unwrap_or_else(|| "fill".into());
@@ -97,15 +114,39 @@ pub fn StateLayer(
 
     let mut state = use_signal(State::default);
 
+    let motion_scheme = use_hook(|| context.peek().motion_scheme);
+    let uses_springs = motion_scheme.uses_springs();
+    let hover = motion_scheme.motion(Easing::Linear, Duration::Short1);
+    let ripple_in = motion_scheme.motion(Easing::Linear, Duration::Short2);
+    let ripple_out = motion_scheme.motion(Easing::Linear, Duration::Short3);
+    let ripple = motion_scheme.motion(Easing::Standard, Duration::Long1);
+    let hover_spring = motion_scheme.effects_spring(SpringSpeed::Fast);
+    let ripple_in_spring = motion_scheme.effects_spring(SpringSpeed::Fast);
+    let ripple_out_spring = motion_scheme.effects_spring(SpringSpeed::Default);
+    let ripple_spring = motion_scheme.spatial_spring(SpringSpeed::Slow);
+
     let transition = use_transition(move |context| {
-        context.add_tween("opacity", 0.0, Curve::LINEAR, 15);
+        context.add_tween("opacity", 0.0, hover.curve, hover.as_millis());
     });
 
-    let ripple_opacity_transition =
-        use_transition(move |context| context.add_tween("opacity", 0.0, Curve::LINEAR, 75));
+    let ripple_opacity_transition = use_transition(move |context| {
+        context.add_tween("opacity", 0.0, ripple_in.curve, ripple_in.as_millis());
+    });
     let ripple_transition = use_transition(move |context| {
-        context.add_tween("radius", 0.0, Curve::FAST_OUT_SLOW_IN, 450);
-        context.add_tween("origin", Point2D::zero(), Curve::FAST_OUT_SLOW_IN, 450);
+        context.add_tween("radius", 0.0, ripple.curve, ripple.as_millis());
+        context.add_tween("origin", Point2D::zero(), ripple.curve, ripple.as_millis());
+    });
+
+    let mut springs = use_springs(move |context| {
+        context.add_spring("opacity", 0.0, hover_spring);
+    });
+
+    let mut ripple_opacity_springs = use_springs(move |context| {
+        context.add_spring("opacity", 0.0, ripple_in_spring);
+    });
+    let mut ripple_springs = use_springs(move |context| {
+        context.add_spring("radius", 0.0, ripple_spring);
+        context.add_spring("origin", Point2D::zero(), ripple_spring);
     });
 
     let onpointerenter = move |_| state.set(State::Hover);
@@ -117,41 +158,155 @@ pub fn StateLayer(
     let onpointerdown = move |event: PointerEvent| {
         let info = RippleInfo::new(event.get_screen_coordinates().to_f32(), size.read().area);
 
-        ripple_opacity_transition.set_duration("opacity", 75);
-        ripple_opacity_transition.forced_set("opacity", 0.0);
-        ripple_transition.forced_set("radius", info.radius);
-        ripple_transition.forced_set("origin", info.position.start);
-        ripple_transition.set("radius", info.radius * info.scale);
-        ripple_transition.set("origin", info.position.end);
-        ripple_opacity_transition.play([("opacity", 0.1)]);
-        ripple_transition.play_all();
+        if uses_springs {
+            ripple_opacity_springs.set_spring("opacity", ripple_in_spring);
+            ripple_opacity_springs.forced_set("opacity", 0.0);
+            ripple_springs.forced_set("radius", info.radius);
+            ripple_springs.forced_set("origin", info.position.start);
+            ripple_springs.set("radius", info.radius * info.scale);
+            ripple_springs.set("origin", info.position.end);
+            ripple_opacity_springs.play([("opacity", 0.1)]);
+            ripple_springs.play_all();
+        } else {
+            ripple_opacity_transition.set_duration("opacity", ripple_in.as_millis());
+            ripple_opacity_transition.forced_set("opacity", 0.0);
+            ripple_transition.forced_set("radius", info.radius);
+            ripple_transition.forced_set("origin", info.position.start);
+            ripple_transition.set("radius", info.radius * info.scale);
+            ripple_transition.set("origin", info.position.end);
+            ripple_opacity_transition.play([("opacity", 0.1)]);
+            ripple_transition.play_all();
+        }
 
         state.set(State::Press);
     };
 
     use_effect(move || {
-        transition.play([("opacity", state.read().opacity())]);
+        let opacity = state.read().opacity();
+
+        if uses_springs {
+            springs.play([("opacity", opacity)]);
+        } else {
+            transition.play([("opacity", opacity)]);
+        }
     });
 
     use_effect(move || {
-        if !ripple_transition.is_playing() && !state.read().is_press() {
-            ripple_opacity_transition.set_duration("opacity", 150);
+        if uses_springs {
+            if !ripple_springs.is_playing() && !state.read().is_press() {
+                ripple_opacity_springs.set_spring("opacity", ripple_out_spring);
+                ripple_opacity_springs.play([("opacity", 0.0)]);
+            }
+        } else if !ripple_transition.is_playing() && !state.read().is_press() {
+            ripple_opacity_transition.set_duration("opacity", ripple_out.as_millis());
             ripple_opacity_transition.play([("opacity", 0.0)]);
         }
     });
 
-    let opacity: f32 = transition.get("opacity");
-    let (ripple_opacity, radius, origin): (f32, f32, Point2D) = (
-        ripple_opacity_transition.get("opacity"),
-        ripple_transition.get("radius"),
-        ripple_transition.get("origin"),
+    let (opacity, ripple_opacity, radius, origin): (f32, f32, f32, Point2D) = if uses_springs {
+        (
+            springs.get("opacity"),
+            ripple_opacity_springs.get("opacity"),
+            ripple_springs.get("radius"),
+            ripple_springs.get("origin"),
+        )
+    } else {
+        (
+            transition.get("opacity"),
+            ripple_opacity_transition.get("opacity"),
+            ripple_transition.get("radius"),
+            ripple_transition.get("origin"),
+        )
+    };
+
+    // Cut corners and morphs can't be clipped by the element, so both layers
+    // are drawn on a canvas clipped to the outline instead.
+    let canvas = use_canvas_with_deps(
+        &(
+            outline,
+            canvas_color,
+            opacity,
+            ripple_opacity,
+            radius,
+            origin,
+        ),
+        |(outline, color, opacity, ripple_opacity, radius, origin)| {
+            Box::new(move |context| {
+                let Some(outline) = &outline else {
+                    return;
+                };
+
+                let bounds = skia_safe::Rect::new(
+                    context.area.min_x(),
+                    context.area.min_y(),
+                    context.area.max_x(),
+                    context.area.max_y(),
+                );
+
+                context.canvas.save();
+                context
+                    .canvas
+                    .clip_path(&outline.path(bounds), ClipOp::Intersect, true);
+
+                let mut paint = Paint::default();
+
+                paint
+                    .set_anti_alias(true)
+                    .set_color(color)
+                    .set_alpha_f(opacity);
+                context.canvas.draw_rect(bounds, &paint);
+
+                let center = (
+                    bounds.left + origin.x + radius / 2.0,
+                    bounds.top + origin.y + radius / 2.0,
+                );
+                let shader = Shader::radial_gradient(
+                    center,
+                    radius / 2.0,
+                    GradientShaderColors::Colors(&[color, color, color.with_a(0)]),
+                    Some(&[0.0, 0.65, 1.0][..]),
+                    TileMode::Clamp,
+                    None,
+                    None,
+                );
+                let mut paint = Paint::default();
+
+                paint
+                    .set_anti_alias(true)
+                    .set_shader(shader)
+                    .set_alpha_f(ripple_opacity);
+                context.canvas.draw_circle(center, radius / 2.0, &paint);
+                context.canvas.restore();
+            })
+        },
     );
 
+    if outline.is_some() {
+        return rsx! {
+            rect {
+                width: "{width}",
+                height: "{height}",
+                position: "absolute",
+                position_left,
+                position_top,
+                layer: "-999",
+                canvas_reference: canvas.attribute(),
+
+                reference,
+
+                onpointerenter,
+                onpointerleave,
+                onpointerup,
+                onpointerdown,
+            }
+        };
+    }
+
     rsx! {
         rect {
             width: "{width}",
             height: "{height}",
-            corner_radius: shape.map(Shape::into_value),
+            corner_radius,
             position: "absolute",
             position_left,
             position_top,
@@ -1,17 +1,112 @@
 use dioxus_core::AttributeValue;
-use freya::prelude::*;
+use freya::{
+    core::{
+        parsing::Parse,
+        values::{Fill, Shadow},
+    },
+    prelude::*,
+};
+use freya_motion::use_transition;
+use skia_safe::{BlurStyle, Canvas, ClipOp, Color, MaskFilter, Paint, PaintStyle, Rect};
 
-use crate::{material_design::Shape, prelude::Elevation};
+use crate::{
+    material_design::{ColorValue, CornerShape, ElevationMode, MorphShape, ShapeMorph, ShapeValue},
+    prelude::{use_material_context, use_material_theme, Duration, Easing, Elevation},
+};
+
+/// Splits a `border` attribute into its width, alignment and color.
+fn parse_border(value: &str) -> Option<(f32, &str, Color)> {
+    let mut parts = value.splitn(3, ' ');
+    let width = parts.next()?.parse().ok()?;
+    let alignment = parts.next()?;
+    let color = Color::parse(parts.next()?).ok()?;
+
+    Some((width, alignment, color))
+}
+
+/// Draws what the `rect` element would for shapes it can't represent with a
+/// corner radius.
+fn draw_shape(
+    canvas: &Canvas,
+    shape: &ShapeMorph,
+    bounds: Rect,
+    background: Option<Color>,
+    border: Option<&str>,
+    shadows: &[Shadow],
+) {
+    let path = shape.path(bounds);
+
+    for shadow in shadows {
+        let Fill::Color(color) = &shadow.fill else {
+            continue;
+        };
+
+        let mut paint = Paint::default();
+
+        paint.set_anti_alias(true).set_color(*color);
+
+        if shadow.blur > 0.0 {
+            paint.set_mask_filter(MaskFilter::blur(
+                BlurStyle::Normal,
+                shadow.blur / 2.0,
+                false,
+            ));
+        }
+
+        let shadow_path = shape
+            .path(bounds.with_outset((shadow.spread, shadow.spread)))
+            .with_offset((shadow.x, shadow.y));
+
+        canvas.draw_path(&shadow_path, &paint);
+    }
+
+    if let Some(background) = background {
+        let mut paint = Paint::default();
+
+        paint.set_anti_alias(true).set_color(background);
+
+        canvas.draw_path(&path, &paint);
+    }
+
+    if let Some((width, alignment, color)) = border.and_then(parse_border) {
+        let mut paint = Paint::default();
+
+        paint
+            .set_anti_alias(true)
+            .set_style(PaintStyle::Stroke)
+            .set_color(color);
+
+        canvas.save();
+
+        match alignment {
+            "center" => {
+                paint.set_stroke_width(width);
+            }
+            "outer" => {
+                paint.set_stroke_width(width * 2.0);
+                canvas.clip_path(&path, ClipOp::Difference, true);
+            }
+            _ => {
+                paint.set_stroke_width(width * 2.0);
+                canvas.clip_path(&path, ClipOp::Intersect, true);
+            }
+        }
+
+        canvas.draw_path(&path, &paint);
+        canvas.restore();
+    }
+}
 
 #[component]
 pub fn Surface(
     direction: Option<String>,
     main_align: Option<String>,
     cross_align: Option<String>,
-    background: Option<String>,
-    color: Option<String>,
+    #[props(into)] background: Option<ColorValue>,
+    #[props(into)] color: Option<ColorValue>,
     width: Option<String>,
     height: Option<String>,
+    min_height: Option<String>,
     spacing: Option<String>,
     padding: Option<String>,
     margin: Option<String>,
@@ -19,24 +114,133 @@ pub fn Surface(
     overflow: Option<String>,
     border: Option<String>,
     layer: Option<String>,
-    shape: Option<Shape>,
+    #[props(into)] shape: Option<ShapeValue>,
+    /// Outline drawn instead of `shape`, e.g. from [`use_shape_morph`].
+    morph: Option<ShapeMorph>,
     elevation: Option<Elevation>,
+    /// Whether `elevation` is shown with shadows, a surface tone, or both.
+    /// The tone is only used when `background` isn't set.
+    #[props(default)]
+    elevation_mode: ElevationMode,
+    /// Raises `elevation` by one level while hovered, and lowers it back while
+    /// pressed.
+    #[props(default)]
+    interactive: bool,
     reference: Option<AttributeValue>,
     on_click: Option<EventHandler<MouseEvent>>,
     children: Element,
 ) -> Element {
-    let corner_radius = shape.map(Shape::into_value);
-    let shadow = elevation.map(|elevation| {
-        elevation
-            .into_shadows()
-            .map(|value| {
-                format!(
-                    "{} {} {} {} {}",
-                    value.x, value.y, value.blur, value.spread, value.fill
-                )
-            })
-            .join(", ")
+    let context = use_material_context();
+    let theme = use_material_theme();
+    let theme = theme.read();
+
+    let mut hovered = use_signal(|| false);
+    let mut pressed = use_signal(|| false);
+
+    let target = match elevation {
+        Some(elevation) if interactive && hovered() && !pressed() => elevation.raised(),
+        Some(elevation) => elevation,
+        None => Elevation::Level0,
+    };
+    let level = use_animated_elevation(target);
+    let level = elevation.map(|_| level);
+
+    let background = background.or_else(|| {
+        level
+            .filter(|_| elevation_mode.is_tonal())
+            .map(|level| Elevation::surface_at(level, &theme).into())
+    });
+    let level = level.filter(|_| elevation_mode.has_shadow());
+    let shadow_style = context.read().shadow_style;
+    let color = color.map(|value| value.resolve(&theme));
+    let corners = shape.map(|shape| shape.resolve(&context.read().shape_scale));
+
+    // Cut corners and morphs are drawn on a canvas, so the element itself
+    // stays unstyled. Note that `overflow: "clip"` still clips to the bounding
+    // box.
+    let outline = morph.or_else(|| {
+        corners
+            .filter(CornerShape::is_cut)
+            .map(|corners| ShapeMorph::fixed(MorphShape::Corners(corners)))
     });
+    let canvas = use_canvas_with_deps(
+        &(
+            outline,
+            background.as_ref().map(|value| value.resolve_color(&theme)),
+            border.clone(),
+            level,
+            theme.shadow,
+            shadow_style,
+        ),
+        |(outline, background, border, level, shadow, shadow_style)| {
+            Box::new(move |context| {
+                if let Some(outline) = &outline {
+                    let bounds = Rect::new(
+                        context.area.min_x(),
+                        context.area.min_y(),
+                        context.area.max_x(),
+                        context.area.max_y(),
+                    );
+
+                    draw_shape(
+                        context.canvas,
+                        outline,
+                        bounds,
+                        background,
+                        border.as_deref(),
+                        &level
+                            .into_iter()
+                            .flat_map(|level| Elevation::shadows_at(level, shadow, &shadow_style))
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            })
+        },
+    );
+
+    let onpointerenter = move |_| hovered.set(interactive);
+    let onpointerleave = move |_| {
+        hovered.set(false);
+        pressed.set(false);
+    };
+    let onpointerdown = move |_| pressed.set(interactive);
+    let onpointerup = move |_| pressed.set(false);
+
+    if outline.is_some() {
+        return rsx! {
+            rect {
+                direction,
+                main_align,
+                cross_align,
+                color,
+                width,
+                height,
+                min_height,
+                spacing,
+                padding,
+                margin,
+                opacity,
+                overflow,
+                reference,
+                layer,
+                canvas_reference: canvas.attribute(),
+
+                onpointerenter,
+                onpointerleave,
+                onpointerdown,
+                onpointerup,
+                onclick: move |data| if let Some(handler) = on_click {
+                    handler(data);
+                },
+
+                {children}
+            }
+        };
+    }
+
+    let background = background.map(|value| value.resolve(&theme));
+    let corner_radius = corners.map(|corners| corners.to_string());
+    let shadow = level.map(|level| Elevation::attribute_at(level, theme.shadow, &shadow_style));
 
     rsx! {
         rect {
@@ -47,6 +251,7 @@ pub fn Surface(
             color,
             width,
             height,
+            min_height,
             corner_radius,
             shadow,
             spacing,
@@ -58,6 +263,10 @@ pub fn Surface(
             reference,
             layer,
 
+            onpointerenter,
+            onpointerleave,
+            onpointerdown,
+            onpointerup,
             onclick: move |data| if let Some(handler) = on_click {
                 handler(data);
             },
@@ -66,3 +275,64 @@ pub fn Surface(
         }
     }
 }
+
+/// Animates between shapes whenever `shape` changes, for the `morph` prop of
+/// [`Surface`] and [`StateLayer`]. A change part way through a morph starts
+/// from the current outline. The timing comes from the motion scheme
+/// at the time the hook is first called.
+#[must_use]
+pub fn use_shape_morph(shape: MorphShape) -> ShapeMorph {
+    let mut morph = use_signal(|| ShapeMorph::fixed(shape));
+    let motion = use_material_context()
+        .peek()
+        .motion_scheme
+        .motion(Easing::Standard, Duration::Medium2);
+    let transition = use_transition(move |context| {
+        context.add_tween("progress", 1.0, motion.curve, motion.as_millis());
+    });
+
+    use_effect(use_reactive!(|shape| {
+        let current = *morph.peek();
+
+        if current.to != shape {
+            let current = ShapeMorph {
+                progress: transition.get("progress"),
+                ..current
+            };
+
+            morph.set(current.retarget(shape));
+            transition.forced_set("progress", 0.0);
+            transition.play([("progress", 1.0)]);
+        }
+    }));
+
+    ShapeMorph {
+        progress: transition.get("progress"),
+        ..morph()
+    }
+}
+
+/// Animates between elevation levels whenever `elevation` changes, returning
+/// the current level in the units of [`Elevation::as_value`]. The timing
+/// comes from the motion scheme at the time the hook is first called.
+#[must_use]
+pub fn use_animated_elevation(elevation: Elevation) -> f32 {
+    let motion = use_material_context()
+        .peek()
+        .motion_scheme
+        .motion(Easing::Standard, Duration::Medium1);
+    let transition = use_transition(move |context| {
+        context.add_tween(
+            "level",
+            f32::from(elevation.as_value()),
+            motion.curve,
+            motion.as_millis(),
+        );
+    });
+
+    use_effect(use_reactive!(|elevation| {
+        transition.play([("level", f32::from(elevation.as_value()))]);
+    }));
+
+    transition.get("level")
+}
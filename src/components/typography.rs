@@ -1,27 +1,92 @@
-use crate::material_design::{get_type_scale, TypescaleSize, TypescaleVariant};
+use crate::{
+    material_design::{ColorValue, TypeStyle, TypescaleSize, TypescaleVariant},
+    use_material_context, use_material_theme, ArgbExt, MaterialTheme,
+};
 use freya::prelude::*;
 
+/// A part of a paragraph, which inherits the role of its [`Typography`] unless
+/// overridden.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub variant: Option<TypescaleVariant>,
+    pub size: Option<TypescaleSize>,
+    pub prominent: Option<bool>,
+    pub color: Option<ColorValue>,
+}
+
+impl TextSpan {
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+}
+
+fn resolve_style(
+    theme: &MaterialTheme,
+    variant: TypescaleVariant,
+    size: TypescaleSize,
+    prominent: bool,
+) -> (TypeStyle, String) {
+    let style = theme
+        .type_scale
+        .get(variant, size, prominent)
+        .scaled(theme.font_scale);
+
+    (style, theme.typefaces.resolve(style.typeface).to_owned())
+}
+
 #[component]
 pub fn Typography(
     #[props(default = TypescaleVariant::Body)] variant: TypescaleVariant,
     #[props(default = TypescaleSize::Medium)] size: TypescaleSize,
-    #[props(default)] prominent: bool,
-    color: Option<String>,
+    /// Uses the emphasized style of the role.
+    #[props(default)]
+    prominent: bool,
+    #[props(into)] color: Option<ColorValue>,
     text_align: Option<String>,
     text_shadow: Option<String>,
     max_lines: Option<String>,
+    /// Renders a paragraph made of these spans instead of `children`.
+    spans: Option<Vec<TextSpan>>,
+    /// Allows selecting the text of `spans` with the mouse.
+    #[props(default)]
+    selectable: bool,
     children: Element,
 ) -> Element {
-    let (font_family, weight, size, tracking, line_height) =
-        get_type_scale(variant, size, prominent);
+    let context = use_material_context();
+    let theme = use_material_theme();
+
+    if let Some(spans) = spans {
+        return rsx! {
+            TypographyParagraph {
+                variant,
+                size,
+                prominent,
+                color,
+                text_align,
+                text_shadow,
+                max_lines,
+                spans,
+                selectable,
+            }
+        };
+    }
+
+    let color = color.map(|value| value.resolve(&theme.read()));
+    let (style, font_family) = resolve_style(&context.read(), variant, size, prominent);
 
     rsx! {
         label {
             font_family,
-            font_weight: "{weight}",
-            font_size: "{size}",
-            letter_spacing: "{tracking}",
-            line_height: "{line_height / size}",
+            font_weight: "{style.weight}",
+            font_size: "{style.size}",
+            font_style: if style.italic { "italic" } else { "normal" },
+            letter_spacing: "{style.tracking}",
+            line_height: "{style.line_height / style.size}",
             color,
             text_align,
             text_shadow,
@@ -31,3 +96,112 @@ pub fn Typography(
         }
     }
 }
+
+/// The rich text path of [`Typography`], kept separate so plain labels don't
+/// pay for the editor that tracks the selection.
+#[component]
+fn TypographyParagraph(
+    variant: TypescaleVariant,
+    size: TypescaleSize,
+    prominent: bool,
+    color: Option<ColorValue>,
+    text_align: Option<String>,
+    text_shadow: Option<String>,
+    max_lines: Option<String>,
+    spans: Vec<TextSpan>,
+    selectable: bool,
+) -> Element {
+    let context = use_material_context();
+    let theme = use_material_theme();
+    let color = color.map(|value| value.resolve(&theme.read()));
+    let (style, font_family) = resolve_style(&context.read(), variant, size, prominent);
+
+    let text = spans
+        .iter()
+        .map(|span| span.text.as_str())
+        .collect::<String>();
+
+    let mut editable = use_editable(
+        || EditableConfig::new(text.clone()),
+        EditableMode::MultipleLinesSingleEditor,
+    );
+
+    use_effect(use_reactive!(|text| {
+        if editable.editor().peek().to_string() != text {
+            editable.editor_mut().write().set(&text);
+        }
+    }));
+
+    let spans = spans
+        .into_iter()
+        .map(|span| {
+            let (style, font_family) = resolve_style(
+                &context.read(),
+                span.variant.unwrap_or(variant),
+                span.size.unwrap_or(size),
+                span.prominent.unwrap_or(prominent),
+            );
+            let color = span.color.map(|value| value.resolve(&theme.read()));
+
+            (span.text, style, font_family, color)
+        })
+        .collect::<Vec<_>>();
+
+    let highlight_color = theme.read().primary.with_alpha_f32(0.4).as_rgba();
+
+    let onmousedown = move |event: MouseEvent| {
+        if selectable {
+            editable.process_event(&EditableEvent::MouseDown(event.data, 0));
+        }
+    };
+
+    let onmousemove = move |event: MouseEvent| {
+        if selectable {
+            editable.process_event(&EditableEvent::MouseMove(event.data, 0));
+        }
+    };
+
+    let onclick = move |_| {
+        if selectable {
+            editable.process_event(&EditableEvent::Click);
+        }
+    };
+
+    rsx! {
+        paragraph {
+            font_family,
+            font_weight: "{style.weight}",
+            font_size: "{style.size}",
+            font_style: if style.italic { "italic" } else { "normal" },
+            letter_spacing: "{style.tracking}",
+            line_height: "{style.line_height / style.size}",
+            color,
+            text_align,
+            text_shadow,
+            max_lines,
+            text_overflow: "ellipsis",
+            cursor_reference: editable.cursor_attr(),
+            cursor_id: "0",
+            cursor_mode: "editable",
+            highlights: editable.highlights_attr(0),
+            highlight_color,
+
+            onmousedown,
+            onmousemove,
+            onclick,
+
+            for (content, style, font_family, color) in spans {
+                text {
+                    font_family,
+                    font_weight: "{style.weight}",
+                    font_size: "{style.size}",
+                    font_style: if style.italic { "italic" } else { "normal" },
+                    letter_spacing: "{style.tracking}",
+                    color,
+
+                    {content}
+                }
+            }
+        }
+    }
+}
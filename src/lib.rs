@@ -8,53 +8,138 @@
 
 mod components;
 pub mod material_design;
+mod theme;
 
 pub mod prelude {
     pub use crate::{
         components::*,
         material_design::{
-            motion::{Easing, EasingDuration},
-            Elevation, Shape, TypescaleSize, TypescaleVariant,
+            motion::{Duration, Easing, EasingDuration, Motion, MotionScheme, Spring, SpringSpeed},
+            ColorRole, ColorValue, CornerFamily, CornerShape, Elevation, ElevationMode, MorphShape,
+            Shape, ShapeMorph, ShapeScale, ShapeValue, TypeScale, TypeStyle, Typeface, Typefaces,
+            TypescaleSize, TypescaleVariant,
         },
-        set_material_theme, use_material_theme, ArgbExt, LaunchConfigExt,
+        theme::{
+            add_extended_color, load_material_theme_json, seed_colors_from_image,
+            seed_colors_from_pixels, set_material_contrast, set_material_font_scale,
+            set_material_motion_scheme, set_material_seed, set_material_seed_from_image,
+            set_material_shadow_style, set_material_shape_scale, set_material_theme,
+            set_material_type_scale, set_material_typefaces, set_theme_mode, use_material_context,
+            use_material_is_dark, use_material_palettes, use_material_theme,
+            use_persisted_material_theme, ColorGroup, Contrast, ExtendedColor, ImageError,
+            MaterialPalettes, MaterialTheme, ThemeJsonError, ThemeMode, ThemePersistence,
+            ThemeSettings, ThemeSettingsError,
+        },
+        ArgbExt, LaunchConfigExt, SchemeVariant,
     };
 }
 
-use freya::prelude::{try_use_context, use_context_provider, LaunchConfig, Signal, Writable};
-use material_colors::{color::Argb, scheme::Scheme, theme::ThemeBuilder};
-
-static ROBOTO_THIN: &[u8] = include_bytes!("../assets/Roboto-Thin.ttf");
-static ROBOTO_THIN_ITALIC: &[u8] = include_bytes!("../assets/Roboto-ThinItalic.ttf");
-static ROBOTO_LIGHT: &[u8] = include_bytes!("../assets/Roboto-Light.ttf");
-static ROBOTO_LIGHT_ITALIC: &[u8] = include_bytes!("../assets/Roboto-LightItalic.ttf");
-static ROBOTO_REGULAR: &[u8] = include_bytes!("../assets/Roboto-Regular.ttf");
-static ROBOTO_ITALIC: &[u8] = include_bytes!("../assets/Roboto-Italic.ttf");
-static ROBOTO_MEDIUM: &[u8] = include_bytes!("../assets/Roboto-Medium.ttf");
-static ROBOTO_MEDIUM_ITALIC: &[u8] = include_bytes!("../assets/Roboto-MediumItalic.ttf");
-static ROBOTO_BOLD: &[u8] = include_bytes!("../assets/Roboto-Bold.ttf");
-static ROBOTO_BOLD_ITALIC: &[u8] = include_bytes!("../assets/Roboto-BoldItalic.ttf");
-static ROBOTO_BLACK: &[u8] = include_bytes!("../assets/Roboto-Black.ttf");
-static ROBOTO_BLACK_ITALIC: &[u8] = include_bytes!("../assets/Roboto-BlackItalic.ttf");
+pub use material_colors::dynamic_color::Variant as SchemeVariant;
+pub use theme::{
+    add_extended_color, load_material_theme_json, seed_colors_from_image, seed_colors_from_pixels,
+    set_material_contrast, set_material_font_scale, set_material_motion_scheme, set_material_seed,
+    set_material_seed_from_image, set_material_shadow_style, set_material_shape_scale,
+    set_material_theme, set_material_type_scale, set_material_typefaces, set_theme_mode,
+    use_material_context, use_material_is_dark, use_material_palettes, use_material_theme,
+    use_persisted_material_theme, ColorGroup, Contrast, ExtendedColor, ImageError,
+    MaterialPalettes, MaterialTheme, ThemeJsonError, ThemeMode, ThemePersistence, ThemeSettings,
+    ThemeSettingsError,
+};
+
+use std::{fs, io, path::Path};
+
+use freya::{core::values::Fill, prelude::LaunchConfig};
+use material_colors::{blend, color::Argb, hct::Hct};
+use skia_safe::Color;
+
+static ROBOTO: &[&[u8]] = &[
+    #[cfg(feature = "roboto-thin")]
+    include_bytes!("../assets/Roboto-Thin.ttf"),
+    #[cfg(feature = "roboto-thin")]
+    include_bytes!("../assets/Roboto-ThinItalic.ttf"),
+    #[cfg(feature = "roboto-light")]
+    include_bytes!("../assets/Roboto-Light.ttf"),
+    #[cfg(feature = "roboto-light")]
+    include_bytes!("../assets/Roboto-LightItalic.ttf"),
+    #[cfg(feature = "roboto-regular")]
+    include_bytes!("../assets/Roboto-Regular.ttf"),
+    #[cfg(feature = "roboto-regular")]
+    include_bytes!("../assets/Roboto-Italic.ttf"),
+    #[cfg(feature = "roboto-medium")]
+    include_bytes!("../assets/Roboto-Medium.ttf"),
+    #[cfg(feature = "roboto-medium")]
+    include_bytes!("../assets/Roboto-MediumItalic.ttf"),
+    #[cfg(feature = "roboto-bold")]
+    include_bytes!("../assets/Roboto-Bold.ttf"),
+    #[cfg(feature = "roboto-bold")]
+    include_bytes!("../assets/Roboto-BoldItalic.ttf"),
+    #[cfg(feature = "roboto-black")]
+    include_bytes!("../assets/Roboto-Black.ttf"),
+    #[cfg(feature = "roboto-black")]
+    include_bytes!("../assets/Roboto-BlackItalic.ttf"),
+];
+
+fn is_font_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            ["ttf", "otf", "ttc"]
+                .iter()
+                .any(|value| extension.eq_ignore_ascii_case(value))
+        })
+}
 
 pub trait LaunchConfigExt {
+    /// Adds the `Roboto` weights enabled through the `roboto-*` cargo
+    /// features.
     #[must_use]
     fn with_roboto(self) -> Self;
+
+    /// Loads the font at `path` under the `family` name, e.g. a variable font
+    /// like `Roboto Flex`. If `path` is a directory, every font file inside it
+    /// is loaded.
+    ///
+    /// The family can then be used through
+    /// [`Typefaces`](crate::material_design::Typefaces).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` or one of the files in it can't be read.
+    fn with_material_fonts(self, family: &'static str, path: impl AsRef<Path>) -> io::Result<Self>
+    where
+        Self: Sized;
 }
 
 impl<T: Clone> LaunchConfigExt for LaunchConfig<'_, T> {
     fn with_roboto(self) -> Self {
-        self.with_font("Roboto", ROBOTO_THIN)
-            .with_font("Roboto", ROBOTO_THIN_ITALIC)
-            .with_font("Roboto", ROBOTO_LIGHT)
-            .with_font("Roboto", ROBOTO_LIGHT_ITALIC)
-            .with_font("Roboto", ROBOTO_REGULAR)
-            .with_font("Roboto", ROBOTO_ITALIC)
-            .with_font("Roboto", ROBOTO_MEDIUM)
-            .with_font("Roboto", ROBOTO_MEDIUM_ITALIC)
-            .with_font("Roboto", ROBOTO_BOLD)
-            .with_font("Roboto", ROBOTO_BOLD_ITALIC)
-            .with_font("Roboto", ROBOTO_BLACK)
-            .with_font("Roboto", ROBOTO_BLACK_ITALIC)
+        ROBOTO
+            .iter()
+            .fold(self, |config, font| config.with_font("Roboto", font))
+    }
+
+    fn with_material_fonts(self, family: &'static str, path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        let mut files = if path.is_dir() {
+            fs::read_dir(path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<Vec<_>>>()?
+                .into_iter()
+                .filter(|path| is_font_file(path))
+                .collect()
+        } else {
+            vec![path.to_path_buf()]
+        };
+
+        files.sort();
+
+        files.into_iter().try_fold(self, |config, path| {
+            // Fonts have to outlive the launch config, which borrows them for the
+            // whole lifetime of the app.
+            let font: &'static [u8] = fs::read(path)?.leak();
+
+            Ok(config.with_font(family, font))
+        })
     }
 }
 
@@ -62,6 +147,31 @@ pub trait ArgbExt {
     fn as_rgba(&self) -> String;
     #[must_use]
     fn with_alpha_f32(self, alpha: f32) -> Self;
+
+    fn hue(&self) -> f64;
+    fn chroma(&self) -> f64;
+    fn tone(&self) -> f64;
+    #[must_use]
+    fn with_hue(self, hue: f64) -> Self;
+    #[must_use]
+    fn with_chroma(self, chroma: f64) -> Self;
+    #[must_use]
+    fn with_tone(self, tone: f64) -> Self;
+
+    /// Blends towards `other` in CAM16-UCS, where `amount` of `0.0` keeps the
+    /// color and `1.0` returns `other`.
+    #[must_use]
+    fn blend(self, other: Self, amount: f64) -> Self;
+    /// Shifts the hue towards `source`, so the color fits better with it.
+    #[must_use]
+    fn harmonize(self, source: Self) -> Self;
+    /// Interpolates each channel towards `other`, for animating between
+    /// colors.
+    #[must_use]
+    fn lerp(self, other: Self, progress: f32) -> Self;
+
+    fn as_color(&self) -> Color;
+    fn as_fill(&self) -> Fill;
 }
 
 impl ArgbExt for Argb {
@@ -73,29 +183,75 @@ impl ArgbExt for Argb {
     }
 
     fn with_alpha_f32(mut self, alpha: f32) -> Self {
-        self.alpha = (255.0 * alpha) as u8;
+        self.alpha = (255.0 * alpha).round() as u8;
 
         self
     }
-}
 
-#[must_use]
-pub fn use_material_theme() -> Signal<Scheme> {
-    try_use_context::<Signal<Scheme>>().map_or_else(
-        || {
-            use_context_provider(|| {
-                Signal::new(
-                    ThemeBuilder::with_source(Argb::from_u32(0xFFBC0D))
-                        .build()
-                        .schemes
-                        .dark,
-                )
-            })
-        },
-        |value| value,
-    )
-}
+    fn hue(&self) -> f64 {
+        Hct::new(*self).get_hue()
+    }
 
-pub fn set_material_theme(scheme: Scheme) {
-    *use_material_theme().write() = scheme;
+    fn chroma(&self) -> f64 {
+        Hct::new(*self).get_chroma()
+    }
+
+    fn tone(&self) -> f64 {
+        Hct::new(*self).get_tone()
+    }
+
+    fn with_hue(self, hue: f64) -> Self {
+        let mut hct = Hct::new(self);
+
+        hct.set_hue(hue);
+
+        Self::from(hct)
+    }
+
+    fn with_chroma(self, chroma: f64) -> Self {
+        let mut hct = Hct::new(self);
+
+        hct.set_chroma(chroma);
+
+        Self::from(hct)
+    }
+
+    fn with_tone(self, tone: f64) -> Self {
+        let mut hct = Hct::new(self);
+
+        hct.set_tone(tone);
+
+        Self::from(hct)
+    }
+
+    fn blend(self, other: Self, amount: f64) -> Self {
+        blend::cam16_ucs(self, other, amount)
+    }
+
+    fn harmonize(self, source: Self) -> Self {
+        blend::harmonize(self, source)
+    }
+
+    fn lerp(self, other: Self, progress: f32) -> Self {
+        let lerp = |from: u8, to: u8| {
+            f32::from(to)
+                .mul_add(progress, f32::from(from) * (1.0 - progress))
+                .round() as u8
+        };
+
+        Self::new(
+            lerp(self.alpha, other.alpha),
+            lerp(self.red, other.red),
+            lerp(self.green, other.green),
+            lerp(self.blue, other.blue),
+        )
+    }
+
+    fn as_color(&self) -> Color {
+        Color::from_argb(self.alpha, self.red, self.green, self.blue)
+    }
+
+    fn as_fill(&self) -> Fill {
+        Fill::Color(self.as_color())
+    }
 }
@@ -1,5 +1,172 @@
-use freya::core::values::{Fill, Shadow, ShadowPosition};
-use skia_safe::Color;
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    fmt,
+};
+
+use freya::core::{
+    parsing::Parse,
+    values::{CornerRadius, Fill, Shadow, ShadowPosition},
+};
+use material_colors::{color::Argb, scheme::Scheme};
+use skia_safe::{
+    stroke_rec::InitStyle, Color, ContourMeasureIter, Path, PathEffect, Point, RRect, Rect,
+    StrokeRec,
+};
+
+use crate::ArgbExt;
+
+/// Generates [`ColorRole`] and its lookups from a single table of role,
+/// [`Scheme`] field and Material Theme Builder name.
+macro_rules! color_roles {
+    ($($role:ident => $field:ident, $name:literal;)*) => {
+        /// A color role of [`Scheme`], resolved against the active theme.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum ColorRole {
+            $($role,)*
+        }
+
+        impl ColorRole {
+            pub const ALL: [Self; [$($name),*].len()] = [$(Self::$role),*];
+
+            #[must_use]
+            pub const fn resolve(self, scheme: &Scheme) -> Argb {
+                match self {
+                    $(Self::$role => scheme.$field,)*
+                }
+            }
+
+            pub const fn resolve_mut(self, scheme: &mut Scheme) -> &mut Argb {
+                match self {
+                    $(Self::$role => &mut scheme.$field,)*
+                }
+            }
+
+            /// Returns the name of the role as used by the Material Theme Builder.
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$role => $name,)*
+                }
+            }
+
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$role),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+color_roles! {
+    Primary => primary, "primary";
+    OnPrimary => on_primary, "onPrimary";
+    PrimaryContainer => primary_container, "primaryContainer";
+    OnPrimaryContainer => on_primary_container, "onPrimaryContainer";
+    InversePrimary => inverse_primary, "inversePrimary";
+    PrimaryFixed => primary_fixed, "primaryFixed";
+    PrimaryFixedDim => primary_fixed_dim, "primaryFixedDim";
+    OnPrimaryFixed => on_primary_fixed, "onPrimaryFixed";
+    OnPrimaryFixedVariant => on_primary_fixed_variant, "onPrimaryFixedVariant";
+    Secondary => secondary, "secondary";
+    OnSecondary => on_secondary, "onSecondary";
+    SecondaryContainer => secondary_container, "secondaryContainer";
+    OnSecondaryContainer => on_secondary_container, "onSecondaryContainer";
+    SecondaryFixed => secondary_fixed, "secondaryFixed";
+    SecondaryFixedDim => secondary_fixed_dim, "secondaryFixedDim";
+    OnSecondaryFixed => on_secondary_fixed, "onSecondaryFixed";
+    OnSecondaryFixedVariant => on_secondary_fixed_variant, "onSecondaryFixedVariant";
+    Tertiary => tertiary, "tertiary";
+    OnTertiary => on_tertiary, "onTertiary";
+    TertiaryContainer => tertiary_container, "tertiaryContainer";
+    OnTertiaryContainer => on_tertiary_container, "onTertiaryContainer";
+    TertiaryFixed => tertiary_fixed, "tertiaryFixed";
+    TertiaryFixedDim => tertiary_fixed_dim, "tertiaryFixedDim";
+    OnTertiaryFixed => on_tertiary_fixed, "onTertiaryFixed";
+    OnTertiaryFixedVariant => on_tertiary_fixed_variant, "onTertiaryFixedVariant";
+    Error => error, "error";
+    OnError => on_error, "onError";
+    ErrorContainer => error_container, "errorContainer";
+    OnErrorContainer => on_error_container, "onErrorContainer";
+    SurfaceDim => surface_dim, "surfaceDim";
+    Surface => surface, "surface";
+    SurfaceBright => surface_bright, "surfaceBright";
+    SurfaceContainerLowest => surface_container_lowest, "surfaceContainerLowest";
+    SurfaceContainerLow => surface_container_low, "surfaceContainerLow";
+    SurfaceContainer => surface_container, "surfaceContainer";
+    SurfaceContainerHigh => surface_container_high, "surfaceContainerHigh";
+    SurfaceContainerHighest => surface_container_highest, "surfaceContainerHighest";
+    SurfaceVariant => surface_variant, "surfaceVariant";
+    SurfaceTint => surface_tint, "surfaceTint";
+    OnSurface => on_surface, "onSurface";
+    OnSurfaceVariant => on_surface_variant, "onSurfaceVariant";
+    InverseSurface => inverse_surface, "inverseSurface";
+    InverseOnSurface => inverse_on_surface, "inverseOnSurface";
+    Outline => outline, "outline";
+    OutlineVariant => outline_variant, "outlineVariant";
+    Background => background, "background";
+    OnBackground => on_background, "onBackground";
+    Shadow => shadow, "shadow";
+    Scrim => scrim, "scrim";
+}
+
+/// A color accepted by the components, which is either a [`ColorRole`] that
+/// follows theme changes, a fixed [`Argb`] color, or any value supported by the
+/// `color` attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ColorValue {
+    Role(ColorRole),
+    Argb(Argb),
+    Raw(String),
+}
+
+impl ColorValue {
+    #[must_use]
+    pub fn resolve(&self, scheme: &Scheme) -> String {
+        match self {
+            Self::Role(role) => role.resolve(scheme).as_rgba(),
+            Self::Argb(color) => color.as_rgba(),
+            Self::Raw(value) => value.clone(),
+        }
+    }
+
+    /// Resolves this value into a Skia color, for components that draw on a
+    /// canvas. Raw values that fail to parse are transparent.
+    #[must_use]
+    pub fn resolve_color(&self, scheme: &Scheme) -> Color {
+        match self {
+            Self::Role(role) => role.resolve(scheme).as_color(),
+            Self::Argb(color) => color.as_color(),
+            Self::Raw(value) => Color::parse(value).unwrap_or(Color::TRANSPARENT),
+        }
+    }
+}
+
+impl From<ColorRole> for ColorValue {
+    fn from(value: ColorRole) -> Self {
+        Self::Role(value)
+    }
+}
+
+impl From<Argb> for ColorValue {
+    fn from(value: Argb) -> Self {
+        Self::Argb(value)
+    }
+}
+
+impl From<String> for ColorValue {
+    fn from(value: String) -> Self {
+        Self::Raw(value)
+    }
+}
+
+impl From<&str> for ColorValue {
+    fn from(value: &str) -> Self {
+        Self::Raw(value.to_owned())
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Elevation {
@@ -12,6 +179,15 @@ pub enum Elevation {
 }
 
 impl Elevation {
+    pub const ALL: [Self; 6] = [
+        Self::Level0,
+        Self::Level1,
+        Self::Level2,
+        Self::Level3,
+        Self::Level4,
+        Self::Level5,
+    ];
+
     #[must_use]
     pub const fn as_value(&self) -> u8 {
         match self {
@@ -29,9 +205,61 @@ impl Elevation {
         self.as_value()
     }
 
-    fn calc(self) -> (f32, f32, Color, f32, f32, f32, Color) {
-        let level = f32::from(self.as_value());
+    /// Returns the surface role expressing this level through tone.
+    #[must_use]
+    pub const fn surface_role(self) -> ColorRole {
+        match self {
+            Self::Level0 => ColorRole::Surface,
+            Self::Level1 => ColorRole::SurfaceContainerLow,
+            Self::Level2 => ColorRole::SurfaceContainer,
+            Self::Level3 => ColorRole::SurfaceContainerHigh,
+            Self::Level4 | Self::Level5 => ColorRole::SurfaceContainerHighest,
+        }
+    }
+
+    /// Returns the level above this one, used while hovered.
+    #[must_use]
+    pub const fn raised(self) -> Self {
+        match self {
+            Self::Level0 => Self::Level1,
+            Self::Level1 => Self::Level2,
+            Self::Level2 => Self::Level3,
+            Self::Level3 => Self::Level4,
+            Self::Level4 | Self::Level5 => Self::Level5,
+        }
+    }
 
+    /// Returns the surface color of a level part way between two levels, in
+    /// the units of [`Elevation::as_value`].
+    #[must_use]
+    pub fn surface_at(level: f32, scheme: &Scheme) -> Argb {
+        let (mut from, mut to) = (Self::Level0, Self::Level0);
+
+        for elevation in Self::ALL {
+            if f32::from(elevation.as_value()) <= level {
+                from = elevation;
+            }
+
+            to = elevation;
+
+            if f32::from(elevation.as_value()) >= level {
+                break;
+            }
+        }
+
+        let (start, end) = (f32::from(from.as_value()), f32::from(to.as_value()));
+        let progress = if end > start {
+            (level - start) / (end - start)
+        } else {
+            1.0
+        };
+
+        from.surface_role()
+            .resolve(scheme)
+            .lerp(to.surface_role().resolve(scheme), progress)
+    }
+
+    fn calc(level: f32, key: Color, ambient: Color) -> (f32, f32, Color, f32, f32, f32, Color) {
         let (y1, blur1, color1) = {
             let level1_y = level.clamp(0.0, 1.0);
             let level4_y = (level - 3.0).clamp(0.0, 1.0);
@@ -44,7 +272,7 @@ impl Elevation {
             (
                 level1_y + level4_y + level5_y,
                 level1_blur + level3_blur + level5_blur,
-                Color::from_argb(77, 0, 0, 0),
+                key,
             )
         };
 
@@ -61,7 +289,7 @@ impl Elevation {
                 level1_y + level2_y + level3to5_y,
                 level1to2_blur + level3to5_blur,
                 level1to4_spread + level5_spread,
-                Color::from_argb(38, 0, 0, 0),
+                ambient,
             )
         };
 
@@ -69,9 +297,22 @@ impl Elevation {
     }
 
     // Code taken from https://github.com/material-components/material-web/blob/main/elevation/internal/_elevation.scss
+    /// Returns the key and ambient shadows of this level, colored with
+    /// `color`, which is usually the shadow role of the active scheme.
     #[must_use]
-    pub fn as_shadows(&self) -> [Shadow; 2] {
-        let (y1, blur1, color1, y2, blur2, spread, color2) = self.calc();
+    pub fn shadows(self, color: Argb, style: &ShadowStyle) -> [Shadow; 2] {
+        Self::shadows_at(f32::from(self.as_value()), color, style)
+    }
+
+    /// Like [`Elevation::shadows`], for a level part way between two levels,
+    /// in the units of [`Elevation::as_value`].
+    #[must_use]
+    pub fn shadows_at(level: f32, color: Argb, style: &ShadowStyle) -> [Shadow; 2] {
+        let (y1, blur1, color1, y2, blur2, spread, color2) = Self::calc(
+            level,
+            color.with_alpha_f32(style.key_opacity).as_color(),
+            color.with_alpha_f32(style.ambient_opacity).as_color(),
+        );
 
         [
             Shadow {
@@ -93,10 +334,75 @@ impl Elevation {
         ]
     }
 
+    /// Returns the shadows of this level in black at the default
+    /// [`ShadowStyle`], ignoring the theme.
+    #[must_use]
+    pub fn as_shadows(&self) -> [Shadow; 2] {
+        self.shadows(Argb::from_u32(0xFF000000), &ShadowStyle::default())
+    }
+
     #[must_use]
     pub fn into_shadows(self) -> [Shadow; 2] {
         self.as_shadows()
     }
+
+    /// Returns the value of the `shadow` attribute for this level.
+    #[must_use]
+    pub fn as_attribute(self, color: Argb, style: &ShadowStyle) -> String {
+        Self::attribute_at(f32::from(self.as_value()), color, style)
+    }
+
+    /// Like [`Elevation::as_attribute`], for a level part way between two
+    /// levels.
+    #[must_use]
+    pub fn attribute_at(level: f32, color: Argb, style: &ShadowStyle) -> String {
+        Self::shadows_at(level, color, style)
+            .map(|value| {
+                format!(
+                    "{} {} {} {} {}",
+                    value.x, value.y, value.blur, value.spread, value.fill
+                )
+            })
+            .join(", ")
+    }
+}
+
+/// Opacities of the shadow role used for the shadows of an [`Elevation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowStyle {
+    pub key_opacity: f32,
+    pub ambient_opacity: f32,
+}
+
+impl Default for ShadowStyle {
+    fn default() -> Self {
+        Self {
+            key_opacity: 0.3,
+            ambient_opacity: 0.15,
+        }
+    }
+}
+
+/// How an [`Elevation`] is rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElevationMode {
+    #[default]
+    Shadow,
+    /// Uses the surface role of the level as the background.
+    Tonal,
+    TonalWithShadow,
+}
+
+impl ElevationMode {
+    #[must_use]
+    pub const fn is_tonal(self) -> bool {
+        matches!(self, Self::Tonal | Self::TonalWithShadow)
+    }
+
+    #[must_use]
+    pub const fn has_shadow(self) -> bool {
+        matches!(self, Self::Shadow | Self::TonalWithShadow)
+    }
 }
 
 pub mod motion {
@@ -110,6 +416,7 @@ pub mod motion {
         Standard,
         StandardDecelerate,
         StandardAccelerate,
+        Linear,
     }
 
     impl Easing {
@@ -129,6 +436,7 @@ pub mod motion {
                 Self::Standard => Self::STANDARD,
                 Self::StandardDecelerate => Self::STANDARD_DECELERATE,
                 Self::StandardAccelerate => Self::STANDARD_ACCELERATE,
+                Self::Linear => Curve::LINEAR,
             }
         }
 
@@ -162,6 +470,348 @@ pub mod motion {
             self.as_value()
         }
     }
+
+    /// Duration tokens, see
+    /// <https://m3.material.io/styles/motion/easing-and-duration/tokens-specs>.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Duration {
+        Short1,
+        Short2,
+        Short3,
+        Short4,
+        Medium1,
+        Medium2,
+        Medium3,
+        Medium4,
+        Long1,
+        Long2,
+        Long3,
+        Long4,
+        ExtraLong1,
+        ExtraLong2,
+        ExtraLong3,
+        ExtraLong4,
+    }
+
+    impl Duration {
+        #[must_use]
+        pub const fn as_millis(&self) -> u64 {
+            match self {
+                Self::Short1 => 50,
+                Self::Short2 => 100,
+                Self::Short3 => 150,
+                Self::Short4 => 200,
+                Self::Medium1 => 250,
+                Self::Medium2 => 300,
+                Self::Medium3 => 350,
+                Self::Medium4 => 400,
+                Self::Long1 => 450,
+                Self::Long2 => 500,
+                Self::Long3 => 550,
+                Self::Long4 => 600,
+                Self::ExtraLong1 => 700,
+                Self::ExtraLong2 => 800,
+                Self::ExtraLong3 => 900,
+                Self::ExtraLong4 => 1000,
+            }
+        }
+
+        #[must_use]
+        pub const fn as_value(&self) -> std::time::Duration {
+            std::time::Duration::from_millis(self.as_millis())
+        }
+
+        #[must_use]
+        pub const fn into_value(self) -> std::time::Duration {
+            self.as_value()
+        }
+
+        /// Returns the next longer token, or this one if it is the longest.
+        #[must_use]
+        pub const fn longer(self) -> Self {
+            match self {
+                Self::Short1 => Self::Short2,
+                Self::Short2 => Self::Short3,
+                Self::Short3 => Self::Short4,
+                Self::Short4 => Self::Medium1,
+                Self::Medium1 => Self::Medium2,
+                Self::Medium2 => Self::Medium3,
+                Self::Medium3 => Self::Medium4,
+                Self::Medium4 => Self::Long1,
+                Self::Long1 => Self::Long2,
+                Self::Long2 => Self::Long3,
+                Self::Long3 => Self::Long4,
+                Self::Long4 => Self::ExtraLong1,
+                Self::ExtraLong1 => Self::ExtraLong2,
+                Self::ExtraLong2 => Self::ExtraLong3,
+                Self::ExtraLong3 | Self::ExtraLong4 => Self::ExtraLong4,
+            }
+        }
+    }
+
+    /// A curve and duration ready to be used by a `freya-motion` tween.
+    #[derive(Clone, Copy)]
+    pub struct Motion {
+        pub curve: Curve,
+        pub duration: std::time::Duration,
+    }
+
+    impl Motion {
+        /// Returns the duration in the milliseconds expected by `freya-motion`.
+        #[must_use]
+        pub const fn as_millis(&self) -> u64 {
+            self.duration.as_millis() as u64
+        }
+    }
+
+    /// Speed of a spring token.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum SpringSpeed {
+        Fast,
+        Default,
+        Slow,
+    }
+
+    /// A damped spring with unit mass, as used by the M3 Expressive motion
+    /// system.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Spring {
+        pub damping: f32,
+        pub stiffness: f32,
+    }
+
+    impl Spring {
+        /// Longest [`Spring::settling_duration`], reached by springs with
+        /// little or no damping.
+        pub const MAX_SETTLING_DURATION: std::time::Duration = std::time::Duration::from_secs(10);
+
+        #[must_use]
+        pub const fn new(damping: f32, stiffness: f32) -> Self {
+            Self { damping, stiffness }
+        }
+
+        /// Returns how far the spring travels past its target, relative to the
+        /// distance it moves.
+        #[must_use]
+        pub fn overshoot(self) -> f32 {
+            let damping = self.damping.max(0.0);
+
+            if damping >= 1.0 {
+                0.0
+            } else {
+                (-damping * std::f32::consts::PI / damping.mul_add(-damping, 1.0).sqrt()).exp()
+            }
+        }
+
+        /// Returns the time the spring takes to settle within 2% of its target.
+        ///
+        /// A spring without stiffness doesn't move at all, while one without
+        /// damping never settles and is capped at [`Spring::MAX_SETTLING_DURATION`].
+        #[must_use]
+        pub fn settling_duration(self) -> std::time::Duration {
+            if self.stiffness.is_nan() || self.stiffness <= 0.0 {
+                return std::time::Duration::ZERO;
+            }
+
+            std::time::Duration::try_from_secs_f32(3.9 / (self.damping * self.stiffness.sqrt()))
+                .map_or(Self::MAX_SETTLING_DURATION, |duration| {
+                    duration.min(Self::MAX_SETTLING_DURATION)
+                })
+        }
+
+        /// Returns the displacement from the target and the velocity of the
+        /// spring `time` seconds after it was at `displacement` with `velocity`.
+        ///
+        /// Negative damping is treated as none, and a spring without stiffness
+        /// is at its target right away.
+        #[must_use]
+        pub fn solve(self, displacement: f32, velocity: f32, time: f32) -> (f32, f32) {
+            if self.stiffness.is_nan() || self.stiffness <= 0.0 {
+                return (0.0, 0.0);
+            }
+
+            let damping = self.damping.max(0.0);
+            let frequency = self.stiffness.sqrt();
+            let decay = damping * frequency;
+
+            if (damping - 1.0).abs() < 1e-3 {
+                // Critically damped.
+                let rate = frequency.mul_add(displacement, velocity);
+                let envelope = (-frequency * time).exp();
+                let position = rate.mul_add(time, displacement);
+
+                (
+                    position * envelope,
+                    frequency.mul_add(-position, rate) * envelope,
+                )
+            } else if damping < 1.0 {
+                // Underdamped, oscillating around the target.
+                let damped = frequency * damping.mul_add(-damping, 1.0).sqrt();
+                let amplitude = decay.mul_add(displacement, velocity) / damped;
+                let envelope = (-decay * time).exp();
+                let (sin, cos) = (damped * time).sin_cos();
+
+                (
+                    envelope * displacement.mul_add(cos, amplitude * sin),
+                    envelope
+                        * amplitude
+                            .mul_add(damped, -decay * displacement)
+                            .mul_add(cos, -displacement.mul_add(damped, decay * amplitude) * sin),
+                )
+            } else {
+                // Overdamped, creeping towards the target.
+                let spread = frequency * damping.mul_add(damping, -1.0).sqrt();
+                let (slow, fast) = (spread - decay, -spread - decay);
+                let fast_part = slow.mul_add(-displacement, velocity) / (fast - slow);
+                let slow_part = displacement - fast_part;
+                let (slow_decay, fast_decay) = ((slow * time).exp(), (fast * time).exp());
+
+                (
+                    slow_part.mul_add(slow_decay, fast_part * fast_decay),
+                    (slow_part * slow).mul_add(slow_decay, fast_part * fast * fast_decay),
+                )
+            }
+        }
+
+        /// Bakes the spring into a curve and duration for `freya-motion`, for
+        /// tweens that can't use `use_springs`.
+        ///
+        /// The curve starts like [`Easing::Standard`], and its second control
+        /// point is raised until its peak matches the overshoot of the spring.
+        /// It only overshoots once and starts from rest, so it's an
+        /// approximation of the actual spring.
+        #[must_use]
+        pub fn motion(self) -> Motion {
+            // Peak of a cubic bezier with control points at 0 and `y2`.
+            let peak = |y2: f32| {
+                if y2 <= 1.0 {
+                    return 1.0;
+                }
+
+                let t = 2.0 * y2 / 3.0f32.mul_add(y2, -1.0);
+
+                (3.0 * (1.0 - t) * t.powi(2)).mul_add(y2, t.powi(3))
+            };
+
+            let target = 1.0 + self.overshoot();
+            let (mut low, mut high) = (1.0, 4.0);
+
+            for _ in 0..32 {
+                let middle = (low + high) / 2.0;
+
+                if peak(middle) < target {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+
+            Motion {
+                curve: Curve::cubic(0.2, 0.0, 0.0, high),
+                duration: self.settling_duration(),
+            }
+        }
+    }
+
+    /// Which flavor of motion the components of a theme use.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MotionScheme {
+        #[default]
+        Standard,
+        /// Swaps standard easings for their emphasized counterparts and uses
+        /// the next longer duration, for more pronounced transitions.
+        Expressive,
+        /// Like [`MotionScheme::Standard`], with springs for spatial and
+        /// effects motion.
+        StandardSpring,
+        /// Like [`MotionScheme::Expressive`], with bouncier springs for
+        /// spatial motion.
+        ExpressiveSpring,
+    }
+
+    impl MotionScheme {
+        #[must_use]
+        pub const fn is_expressive(self) -> bool {
+            matches!(self, Self::Expressive | Self::ExpressiveSpring)
+        }
+
+        #[must_use]
+        pub const fn uses_springs(self) -> bool {
+            matches!(self, Self::StandardSpring | Self::ExpressiveSpring)
+        }
+
+        /// Returns the easing motion for these tokens, which is used even by
+        /// spring schemes where no spring applies.
+        #[must_use]
+        pub const fn motion(self, easing: Easing, duration: Duration) -> Motion {
+            let (easing, duration) = if self.is_expressive() {
+                (
+                    match easing {
+                        Easing::Standard => Easing::Emphasized,
+                        Easing::StandardDecelerate => Easing::EmphasizedDecelerate,
+                        Easing::StandardAccelerate => Easing::EmphasizedAccelerate,
+                        easing => easing,
+                    },
+                    duration.longer(),
+                )
+            } else {
+                (easing, duration)
+            };
+
+            Motion {
+                curve: easing.into_value(),
+                duration: duration.into_value(),
+            }
+        }
+
+        /// Returns the spring for motion that moves or resizes things.
+        #[must_use]
+        pub const fn spatial_spring(self, speed: SpringSpeed) -> Spring {
+            match (self.is_expressive(), speed) {
+                (false, SpringSpeed::Fast) => Spring::new(0.9, 1400.0),
+                (false, SpringSpeed::Default) => Spring::new(0.9, 700.0),
+                (false, SpringSpeed::Slow) => Spring::new(0.9, 300.0),
+                (true, SpringSpeed::Fast) => Spring::new(0.6, 800.0),
+                (true, SpringSpeed::Default) => Spring::new(0.8, 380.0),
+                (true, SpringSpeed::Slow) => Spring::new(0.8, 200.0),
+            }
+        }
+
+        /// Returns the spring for motion of colors and opacity, which never
+        /// overshoots.
+        #[must_use]
+        pub const fn effects_spring(self, speed: SpringSpeed) -> Spring {
+            match speed {
+                SpringSpeed::Fast => Spring::new(1.0, 3800.0),
+                SpringSpeed::Default => Spring::new(1.0, 1600.0),
+                SpringSpeed::Slow => Spring::new(1.0, 800.0),
+            }
+        }
+
+        /// Returns a tween for spatial motion: the [`Spring::motion`] of the
+        /// spatial spring for `speed` when this scheme uses springs, or the
+        /// easing motion otherwise.
+        #[must_use]
+        pub fn spatial(self, easing: Easing, duration: Duration, speed: SpringSpeed) -> Motion {
+            if self.uses_springs() {
+                self.spatial_spring(speed).motion()
+            } else {
+                self.motion(easing, duration)
+            }
+        }
+
+        /// Returns a tween for effects motion: the [`Spring::motion`] of the
+        /// effects spring for `speed` when this scheme uses springs, or the
+        /// easing motion otherwise.
+        #[must_use]
+        pub fn effects(self, easing: Easing, duration: Duration, speed: SpringSpeed) -> Motion {
+            if self.uses_springs() {
+                self.effects_spring(speed).motion()
+            } else {
+                self.motion(easing, duration)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -181,73 +831,516 @@ pub enum Shape {
 }
 
 impl Shape {
+    /// Returns the corners of this shape using the radii of `scale`.
+    #[must_use]
+    pub const fn corners(self, scale: &ShapeScale) -> CornerShape {
+        let corners = match self {
+            Self::None => CornerShape::all(0.0),
+            Self::ExtraSmall => CornerShape::all(scale.extra_small),
+            Self::ExtraSmallTop => CornerShape::top(scale.extra_small),
+            Self::Small => CornerShape::all(scale.small),
+            Self::Medium => CornerShape::all(scale.medium),
+            Self::Large => CornerShape::all(scale.large),
+            Self::LargeStart => CornerShape::start(scale.large),
+            Self::LargeEnd => CornerShape::end(scale.large),
+            Self::LargeTop => CornerShape::top(scale.large),
+            Self::ExtraLarge => CornerShape::all(scale.extra_large),
+            Self::ExtraLargeTop => CornerShape::top(scale.extra_large),
+            Self::Full => CornerShape::all(CornerShape::FULL),
+        };
+
+        corners.with_family(scale.family)
+    }
+
+    /// Returns the `corner_radius` attribute of this shape using the radii of
+    /// the default [`ShapeScale`], ignoring the theme.
+    #[must_use]
+    pub fn as_value(&self) -> String {
+        self.corners(&ShapeScale::default()).to_string()
+    }
+
+    #[must_use]
+    pub fn into_value(self) -> String {
+        self.as_value()
+    }
+}
+
+/// How the corners of a shape are drawn.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CornerFamily {
+    #[default]
+    Rounded,
+    /// Chamfered corners, where the radius is the length cut from each side.
+    Cut,
+}
+
+/// Radii the [`Shape`] tokens map to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeScale {
+    pub extra_small: f32,
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+    pub extra_large: f32,
+    pub family: CornerFamily,
+}
+
+impl Default for ShapeScale {
+    fn default() -> Self {
+        Self {
+            extra_small: 4.0,
+            small: 8.0,
+            medium: 12.0,
+            large: 16.0,
+            extra_large: 28.0,
+            family: CornerFamily::Rounded,
+        }
+    }
+}
+
+/// Radius of each corner, in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CornerShape {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+    pub family: CornerFamily,
+}
+
+impl CornerShape {
+    /// Radius large enough to fully round any component.
+    pub const FULL: f32 = 9999.0;
+
+    #[must_use]
+    pub const fn new(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+            family: CornerFamily::Rounded,
+        }
+    }
+
+    #[must_use]
+    pub const fn all(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    #[must_use]
+    pub const fn top(radius: f32) -> Self {
+        Self::new(radius, radius, 0.0, 0.0)
+    }
+
+    #[must_use]
+    pub const fn start(radius: f32) -> Self {
+        Self::new(radius, 0.0, 0.0, radius)
+    }
+
+    #[must_use]
+    pub const fn end(radius: f32) -> Self {
+        Self::new(0.0, radius, radius, 0.0)
+    }
+
     #[must_use]
-    pub const fn as_value(&self) -> &'static str {
+    pub const fn with_family(mut self, family: CornerFamily) -> Self {
+        self.family = family;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_cut(&self) -> bool {
+        matches!(self.family, CornerFamily::Cut)
+    }
+
+    /// Builds the outline of these corners within `bounds`. Radii are clamped
+    /// to half of the shortest side.
+    #[must_use]
+    pub fn path(&self, bounds: Rect) -> Path {
+        let max = bounds.width().min(bounds.height()) / 2.0;
+        let [top_left, top_right, bottom_right, bottom_left] = [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+        .map(|radius| radius.clamp(0.0, max));
+
+        match self.family {
+            CornerFamily::Rounded => Path::rrect(
+                RRect::new_rect_radii(
+                    bounds,
+                    &[
+                        Point::new(top_left, top_left),
+                        Point::new(top_right, top_right),
+                        Point::new(bottom_right, bottom_right),
+                        Point::new(bottom_left, bottom_left),
+                    ],
+                ),
+                None,
+            ),
+            CornerFamily::Cut => {
+                let mut path = Path::new();
+
+                path.move_to((bounds.left + top_left, bounds.top))
+                    .line_to((bounds.right - top_right, bounds.top))
+                    .line_to((bounds.right, bounds.top + top_right))
+                    .line_to((bounds.right, bounds.bottom - bottom_right))
+                    .line_to((bounds.right - bottom_right, bounds.bottom))
+                    .line_to((bounds.left + bottom_left, bounds.bottom))
+                    .line_to((bounds.left, bounds.bottom - bottom_left))
+                    .line_to((bounds.left, bounds.top + top_left))
+                    .close();
+
+                path
+            }
+        }
+    }
+}
+
+impl fmt::Display for CornerShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.top_left, self.top_right, self.bottom_right, self.bottom_left
+        )
+    }
+}
+
+/// Converts the radii only. Freya's corner radius is always rounded, so the
+/// [`CornerFamily`] is dropped and [`CornerFamily::Cut`] corners render
+/// rounded; draw those with [`CornerShape::path`] instead.
+impl From<CornerShape> for CornerRadius {
+    fn from(value: CornerShape) -> Self {
+        Self {
+            top_left: value.top_left,
+            top_right: value.top_right,
+            bottom_right: value.bottom_right,
+            bottom_left: value.bottom_left,
+            ..Default::default()
+        }
+    }
+}
+
+/// A shape accepted by components: either a [`Shape`] token resolved against
+/// the theme's [`ShapeScale`], or fixed corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeValue {
+    Token(Shape),
+    Corners(CornerShape),
+}
+
+impl ShapeValue {
+    #[must_use]
+    pub const fn resolve(self, scale: &ShapeScale) -> CornerShape {
         match self {
-            Self::None => "0",
-            Self::ExtraSmall => "4",
-            Self::ExtraSmallTop => "4 4 0 0",
-            Self::Small => "8",
-            Self::Medium => "12",
-            Self::Large => "16",
-            Self::LargeStart => "16 0 0 16",
-            Self::LargeEnd => "0 16 16 0",
-            Self::LargeTop => "16 16 0 0",
-            Self::ExtraLarge => "28",
-            Self::ExtraLargeTop => "28 28 0 0",
-            Self::Full => "9999",
+            Self::Token(shape) => shape.corners(scale),
+            Self::Corners(corners) => corners,
         }
     }
+}
+
+impl From<Shape> for ShapeValue {
+    fn from(value: Shape) -> Self {
+        Self::Token(value)
+    }
+}
+
+impl From<CornerShape> for ShapeValue {
+    fn from(value: CornerShape) -> Self {
+        Self::Corners(value)
+    }
+}
+
+/// A shape that can be morphed into another one with [`ShapeMorph`].
+///
+/// Polygons and stars are inscribed in the bounds, with their first vertex at
+/// the top. `rounding` is the corner radius relative to half of the shortest
+/// side, like the rounded polygons of the Material shapes library.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MorphShape {
+    Circle,
+    Corners(CornerShape),
+    Polygon {
+        sides: u32,
+        rounding: f32,
+    },
+    Star {
+        points: u32,
+        inner_radius: f32,
+        rounding: f32,
+    },
+}
+
+impl MorphShape {
+    /// Number of points both outlines are sampled at while morphing.
+    const SAMPLES: usize = 120;
 
     #[must_use]
-    pub const fn into_value(self) -> &'static str {
-        self.as_value()
+    pub fn path(&self, bounds: Rect) -> Path {
+        let radius = bounds.width().min(bounds.height()) / 2.0;
+
+        match *self {
+            Self::Circle => Path::oval(bounds, None),
+            Self::Corners(corners) => corners.path(bounds),
+            Self::Polygon { sides, rounding } => Self::round(
+                Self::vertices(bounds, (0..sides.max(3)).map(|_| 1.0)),
+                rounding * radius,
+            ),
+            Self::Star {
+                points,
+                inner_radius,
+                rounding,
+            } => Self::round(
+                Self::vertices(
+                    bounds,
+                    (0..points.max(2) * 2).map(|i| if i % 2 == 0 { 1.0 } else { inner_radius }),
+                ),
+                rounding * radius,
+            ),
+        }
+    }
+
+    /// Places a vertex for each radius, evenly spaced clockwise from the top.
+    #[allow(clippy::cast_precision_loss)]
+    fn vertices(bounds: Rect, radii: impl ExactSizeIterator<Item = f32>) -> Path {
+        let (center, step) = (bounds.center(), TAU / radii.len() as f32);
+        let points = radii
+            .enumerate()
+            .map(|(i, radius)| {
+                let angle = (i as f32).mul_add(step, -FRAC_PI_2);
+
+                Point::new(
+                    (radius * bounds.width() / 2.0).mul_add(angle.cos(), center.x),
+                    (radius * bounds.height() / 2.0).mul_add(angle.sin(), center.y),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Path::polygon(&points, true, None, None)
+    }
+
+    fn round(path: Path, radius: f32) -> Path {
+        PathEffect::corner_path(radius)
+            .and_then(|effect| {
+                effect.filter_path(&path, &StrokeRec::new(InitStyle::Fill), path.bounds())
+            })
+            .map_or(path, |(path, _)| path)
+    }
+
+    /// Samples the outline evenly by length, starting from the point closest
+    /// to the top center so that both shapes of a morph line up.
+    #[allow(clippy::cast_precision_loss)]
+    fn sample(&self, bounds: Rect) -> Vec<Point> {
+        let Some(contour) = ContourMeasureIter::new(&self.path(bounds), true, None).next() else {
+            return vec![bounds.center(); Self::SAMPLES];
+        };
+
+        let center = bounds.center();
+        let step = contour.length() / Self::SAMPLES as f32;
+        let mut points = (0..Self::SAMPLES)
+            .map(|i| {
+                contour
+                    .pos_tan(i as f32 * step)
+                    .map_or(center, |(point, _)| point)
+            })
+            .collect::<Vec<_>>();
+
+        let distance_from_top = |point: &Point| {
+            let angle =
+                ((point.y - center.y).atan2(point.x - center.x) + FRAC_PI_2).rem_euclid(TAU);
+
+            angle.min(TAU - angle)
+        };
+
+        if let Some(start) = points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance_from_top(a).total_cmp(&distance_from_top(b)))
+            .map(|(i, _)| i)
+        {
+            points.rotate_left(start);
+        }
+
+        points
+    }
+}
+
+impl From<CornerShape> for MorphShape {
+    fn from(value: CornerShape) -> Self {
+        Self::Corners(value)
+    }
+}
+
+/// An outline part way between two shapes, where `progress` usually comes
+/// from a `freya-motion` tween.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeMorph {
+    pub from: MorphShape,
+    pub to: MorphShape,
+    pub progress: f32,
+    /// Shape that `from` is blended towards, and by how much, when the morph
+    /// starts part way through a previous one (see [`ShapeMorph::retarget`]).
+    pub via: Option<(MorphShape, f32)>,
+}
+
+impl ShapeMorph {
+    #[must_use]
+    pub const fn new(from: MorphShape, to: MorphShape, progress: f32) -> Self {
+        Self {
+            from,
+            to,
+            progress,
+            via: None,
+        }
+    }
+
+    /// A morph that stays at `shape`.
+    #[must_use]
+    pub const fn fixed(shape: MorphShape) -> Self {
+        Self::new(shape, shape, 1.0)
+    }
+
+    /// Starts a morph to `to` from the current outline of this one, so an
+    /// interrupted morph doesn't snap.
+    ///
+    /// The start of a morph blends at most two shapes, so when this morph was
+    /// itself retargeted part way, its least visible shape is dropped.
+    #[must_use]
+    pub fn retarget(&self, to: MorphShape) -> Self {
+        let remaining = 1.0 - self.progress.clamp(0.0, 1.0);
+        let (via, amount) = self.via.unwrap_or((self.from, 0.0));
+        let mut weights: Vec<(MorphShape, f32)> = Vec::with_capacity(3);
+
+        for (shape, weight) in [
+            (self.from, remaining * (1.0 - amount)),
+            (via, remaining * amount),
+            (self.to, 1.0 - remaining),
+        ] {
+            match weights.iter_mut().find(|(other, _)| *other == shape) {
+                Some((_, total)) => *total += weight,
+                None => weights.push((shape, weight)),
+            }
+        }
+
+        weights.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        let (from, from_weight) = weights[0];
+        let via = weights
+            .get(1)
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|&(shape, weight)| (shape, weight / (from_weight + weight)));
+
+        Self {
+            from,
+            to,
+            progress: 0.0,
+            via,
+        }
+    }
+
+    #[must_use]
+    pub fn path(&self, bounds: Rect) -> Path {
+        if (self.progress - 1.0).abs() < f32::EPSILON {
+            return self.to.path(bounds);
+        }
+
+        let start = match self.via {
+            Some((via, amount)) if via != self.from && amount > 0.0 => self
+                .from
+                .sample(bounds)
+                .into_iter()
+                .zip(via.sample(bounds))
+                .map(|(from, via)| from + (via - from) * amount)
+                .collect::<Vec<_>>(),
+            _ if self.from == self.to => return self.to.path(bounds),
+            _ if self.progress.abs() < f32::EPSILON => return self.from.path(bounds),
+            _ => self.from.sample(bounds),
+        };
+
+        let points = start
+            .into_iter()
+            .zip(self.to.sample(bounds))
+            .map(|(from, to)| from + (to - from) * self.progress)
+            .collect::<Vec<_>>();
+
+        Path::polygon(&points, true, None, None)
     }
 }
 
 mod typescale {
-    use super::Typescale;
+    use super::{TypeStyle, Typeface};
 
     pub(super) mod display {
-        use super::Typescale;
+        use super::{TypeStyle, Typeface};
 
-        pub const LARGE: Typescale = ("Roboto", 400, 57, -0.25, 64);
-        pub const MEDIUM: Typescale = ("Roboto", 400, 45, 0.0, 52);
-        pub const SMALL: Typescale = ("Roboto", 400, 36, 0.0, 44);
+        pub const LARGE: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 57.0, -0.25, 64.0);
+        pub const LARGE_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 57.0, -0.25, 64.0);
+        pub const MEDIUM: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 45.0, 0.0, 52.0);
+        pub const MEDIUM_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 45.0, 0.0, 52.0);
+        pub const SMALL: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 36.0, 0.0, 44.0);
+        pub const SMALL_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 36.0, 0.0, 44.0);
     }
 
     pub(super) mod headline {
-        use super::Typescale;
+        use super::{TypeStyle, Typeface};
 
-        pub const LARGE: Typescale = ("Roboto", 400, 32, 0.0, 40);
-        pub const MEDIUM: Typescale = ("Roboto", 400, 28, 0.0, 36);
-        pub const SMALL: Typescale = ("Roboto", 400, 24, 0.0, 32);
+        pub const LARGE: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 32.0, 0.0, 40.0);
+        pub const LARGE_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 32.0, 0.0, 40.0);
+        pub const MEDIUM: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 28.0, 0.0, 36.0);
+        pub const MEDIUM_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 28.0, 0.0, 36.0);
+        pub const SMALL: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 24.0, 0.0, 32.0);
+        pub const SMALL_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 24.0, 0.0, 32.0);
     }
 
     pub(super) mod title {
-        use super::Typescale;
+        use super::{TypeStyle, Typeface};
 
-        pub const LARGE: Typescale = ("Roboto", 400, 22, 0.0, 28);
-        pub const MEDIUM: Typescale = ("Roboto", 500, 16, 0.15, 24);
-        pub const SMALL: Typescale = ("Roboto", 500, 14, 0.1, 20);
+        pub const LARGE: TypeStyle = TypeStyle::new(Typeface::Brand, 400, 22.0, 0.0, 28.0);
+        pub const LARGE_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 500, 22.0, 0.0, 28.0);
+        pub const MEDIUM: TypeStyle = TypeStyle::new(Typeface::Brand, 500, 16.0, 0.15, 24.0);
+        pub const MEDIUM_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 700, 16.0, 0.15, 24.0);
+        pub const SMALL: TypeStyle = TypeStyle::new(Typeface::Brand, 500, 14.0, 0.1, 20.0);
+        pub const SMALL_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Brand, 700, 14.0, 0.1, 20.0);
     }
 
     pub(super) mod body {
-        use super::Typescale;
+        use super::{TypeStyle, Typeface};
 
-        pub const LARGE: Typescale = ("Roboto", 400, 16, 0.5, 24);
-        pub const MEDIUM: Typescale = ("Roboto", 400, 14, 0.25, 20);
-        pub const SMALL: Typescale = ("Roboto", 400, 12, 0.4, 16);
+        pub const LARGE: TypeStyle = TypeStyle::new(Typeface::Plain, 400, 16.0, 0.5, 24.0);
+        pub const LARGE_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Plain, 500, 16.0, 0.5, 24.0);
+        pub const MEDIUM: TypeStyle = TypeStyle::new(Typeface::Plain, 400, 14.0, 0.25, 20.0);
+        pub const MEDIUM_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Plain, 500, 14.0, 0.25, 20.0);
+        pub const SMALL: TypeStyle = TypeStyle::new(Typeface::Plain, 400, 12.0, 0.4, 16.0);
+        pub const SMALL_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Plain, 500, 12.0, 0.4, 16.0);
     }
 
     pub(super) mod label {
-        use super::Typescale;
+        use super::{TypeStyle, Typeface};
 
-        pub const LARGE: Typescale = ("Roboto", 500, 14, 0.1, 20);
-        pub const LARGE_PROMINENT: Typescale = ("Roboto", 700, 14, 0.1, 20);
-        pub const MEDIUM: Typescale = ("Roboto", 500, 12, 0.5, 16);
-        pub const MEDIUM_PROMINENT: Typescale = ("Roboto", 700, 12, 0.5, 16);
-        pub const SMALL: Typescale = ("Roboto", 500, 11, 0.5, 16);
+        pub const LARGE: TypeStyle = TypeStyle::new(Typeface::Plain, 500, 14.0, 0.1, 20.0);
+        pub const LARGE_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Plain, 700, 14.0, 0.1, 20.0);
+        pub const MEDIUM: TypeStyle = TypeStyle::new(Typeface::Plain, 500, 12.0, 0.5, 16.0);
+        pub const MEDIUM_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Plain, 700, 12.0, 0.5, 16.0);
+        pub const SMALL: TypeStyle = TypeStyle::new(Typeface::Plain, 500, 11.0, 0.5, 16.0);
+        pub const SMALL_PROMINENT: TypeStyle =
+            TypeStyle::new(Typeface::Plain, 700, 11.0, 0.5, 16.0);
     }
 }
 
@@ -267,37 +1360,360 @@ pub enum TypescaleSize {
     Small,
 }
 
-/// (Family, Weight, Size, Tracking (?), Line Height)
-pub type Typescale = (&'static str, usize, usize, f32, usize);
+/// Role of the typeface used by a type style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Typeface {
+    /// Used by display, headline and title styles.
+    Brand,
+    /// Used by body and label styles.
+    Plain,
+    /// A specific font family, regardless of the configured [`Typefaces`].
+    Custom(&'static str),
+}
+
+/// Font families used for each [`Typeface`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Typefaces {
+    pub brand: String,
+    pub plain: String,
+}
+
+impl Typefaces {
+    #[must_use]
+    pub fn resolve(&self, typeface: Typeface) -> &str {
+        match typeface {
+            Typeface::Brand => &self.brand,
+            Typeface::Plain => &self.plain,
+            Typeface::Custom(family) => family,
+        }
+    }
+}
+
+impl Default for Typefaces {
+    fn default() -> Self {
+        Self {
+            brand: "Roboto".into(),
+            plain: "Roboto".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypeStyle {
+    pub typeface: Typeface,
+    pub weight: usize,
+    pub size: f32,
+    pub tracking: f32,
+    pub line_height: f32,
+    pub italic: bool,
+}
+
+impl TypeStyle {
+    #[must_use]
+    pub const fn new(
+        typeface: Typeface,
+        weight: usize,
+        size: f32,
+        tracking: f32,
+        line_height: f32,
+    ) -> Self {
+        Self {
+            typeface,
+            weight,
+            size,
+            tracking,
+            line_height,
+            italic: false,
+        }
+    }
+
+    /// Scales the size and line height, e.g. for user text scaling.
+    #[must_use]
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            size: self.size * factor,
+            line_height: self.line_height * factor,
+            ..self
+        }
+    }
+}
 
-pub(crate) const fn get_type_scale(
+const fn default_type_style(
     variant: TypescaleVariant,
     size: TypescaleSize,
     prominent: bool,
-) -> Typescale {
+) -> TypeStyle {
     match (variant, size, prominent) {
-        (TypescaleVariant::Display, TypescaleSize::Large, _) => typescale::display::LARGE,
-        (TypescaleVariant::Display, TypescaleSize::Medium, _) => typescale::display::MEDIUM,
-        (TypescaleVariant::Display, TypescaleSize::Small, _) => typescale::display::SMALL,
+        (TypescaleVariant::Display, TypescaleSize::Large, false) => typescale::display::LARGE,
+        (TypescaleVariant::Display, TypescaleSize::Large, true) => {
+            typescale::display::LARGE_PROMINENT
+        }
+        (TypescaleVariant::Display, TypescaleSize::Medium, false) => typescale::display::MEDIUM,
+        (TypescaleVariant::Display, TypescaleSize::Medium, true) => {
+            typescale::display::MEDIUM_PROMINENT
+        }
+        (TypescaleVariant::Display, TypescaleSize::Small, false) => typescale::display::SMALL,
+        (TypescaleVariant::Display, TypescaleSize::Small, true) => {
+            typescale::display::SMALL_PROMINENT
+        }
 
-        (TypescaleVariant::Headline, TypescaleSize::Large, _) => typescale::headline::LARGE,
-        (TypescaleVariant::Headline, TypescaleSize::Medium, _) => typescale::headline::MEDIUM,
-        (TypescaleVariant::Headline, TypescaleSize::Small, _) => typescale::headline::SMALL,
+        (TypescaleVariant::Headline, TypescaleSize::Large, false) => typescale::headline::LARGE,
+        (TypescaleVariant::Headline, TypescaleSize::Large, true) => {
+            typescale::headline::LARGE_PROMINENT
+        }
+        (TypescaleVariant::Headline, TypescaleSize::Medium, false) => typescale::headline::MEDIUM,
+        (TypescaleVariant::Headline, TypescaleSize::Medium, true) => {
+            typescale::headline::MEDIUM_PROMINENT
+        }
+        (TypescaleVariant::Headline, TypescaleSize::Small, false) => typescale::headline::SMALL,
+        (TypescaleVariant::Headline, TypescaleSize::Small, true) => {
+            typescale::headline::SMALL_PROMINENT
+        }
 
-        (TypescaleVariant::Title, TypescaleSize::Large, _) => typescale::title::LARGE,
-        (TypescaleVariant::Title, TypescaleSize::Medium, _) => typescale::title::MEDIUM,
-        (TypescaleVariant::Title, TypescaleSize::Small, _) => typescale::title::SMALL,
+        (TypescaleVariant::Title, TypescaleSize::Large, false) => typescale::title::LARGE,
+        (TypescaleVariant::Title, TypescaleSize::Large, true) => typescale::title::LARGE_PROMINENT,
+        (TypescaleVariant::Title, TypescaleSize::Medium, false) => typescale::title::MEDIUM,
+        (TypescaleVariant::Title, TypescaleSize::Medium, true) => {
+            typescale::title::MEDIUM_PROMINENT
+        }
+        (TypescaleVariant::Title, TypescaleSize::Small, false) => typescale::title::SMALL,
+        (TypescaleVariant::Title, TypescaleSize::Small, true) => typescale::title::SMALL_PROMINENT,
 
-        (TypescaleVariant::Body, TypescaleSize::Large, _) => typescale::body::LARGE,
-        (TypescaleVariant::Body, TypescaleSize::Medium, _) => typescale::body::MEDIUM,
-        (TypescaleVariant::Body, TypescaleSize::Small, _) => typescale::body::SMALL,
+        (TypescaleVariant::Body, TypescaleSize::Large, false) => typescale::body::LARGE,
+        (TypescaleVariant::Body, TypescaleSize::Large, true) => typescale::body::LARGE_PROMINENT,
+        (TypescaleVariant::Body, TypescaleSize::Medium, false) => typescale::body::MEDIUM,
+        (TypescaleVariant::Body, TypescaleSize::Medium, true) => typescale::body::MEDIUM_PROMINENT,
+        (TypescaleVariant::Body, TypescaleSize::Small, false) => typescale::body::SMALL,
+        (TypescaleVariant::Body, TypescaleSize::Small, true) => typescale::body::SMALL_PROMINENT,
 
-        (TypescaleVariant::Label, TypescaleSize::Large, true) => typescale::label::LARGE_PROMINENT,
         (TypescaleVariant::Label, TypescaleSize::Large, false) => typescale::label::LARGE,
+        (TypescaleVariant::Label, TypescaleSize::Large, true) => typescale::label::LARGE_PROMINENT,
+        (TypescaleVariant::Label, TypescaleSize::Medium, false) => typescale::label::MEDIUM,
         (TypescaleVariant::Label, TypescaleSize::Medium, true) => {
             typescale::label::MEDIUM_PROMINENT
         }
-        (TypescaleVariant::Label, TypescaleSize::Medium, false) => typescale::label::MEDIUM,
-        (TypescaleVariant::Label, TypescaleSize::Small, _) => typescale::label::SMALL,
+        (TypescaleVariant::Label, TypescaleSize::Small, false) => typescale::label::SMALL,
+        (TypescaleVariant::Label, TypescaleSize::Small, true) => typescale::label::SMALL_PROMINENT,
+    }
+}
+
+const VARIANTS: [TypescaleVariant; 5] = [
+    TypescaleVariant::Display,
+    TypescaleVariant::Headline,
+    TypescaleVariant::Title,
+    TypescaleVariant::Body,
+    TypescaleVariant::Label,
+];
+
+const SIZES: [TypescaleSize; 3] = [
+    TypescaleSize::Large,
+    TypescaleSize::Medium,
+    TypescaleSize::Small,
+];
+
+/// Type styles for every variant and size, in both regular and prominent
+/// (emphasized) form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeScale {
+    regular: [[TypeStyle; 3]; 5],
+    prominent: [[TypeStyle; 3]; 5],
+}
+
+impl TypeScale {
+    #[must_use]
+    pub const fn get(
+        &self,
+        variant: TypescaleVariant,
+        size: TypescaleSize,
+        prominent: bool,
+    ) -> TypeStyle {
+        let styles = if prominent {
+            &self.prominent
+        } else {
+            &self.regular
+        };
+
+        styles[variant as usize][size as usize]
+    }
+
+    pub fn set(
+        &mut self,
+        variant: TypescaleVariant,
+        size: TypescaleSize,
+        prominent: bool,
+        style: TypeStyle,
+    ) {
+        let styles = if prominent {
+            &mut self.prominent
+        } else {
+            &mut self.regular
+        };
+
+        styles[variant as usize][size as usize] = style;
+    }
+}
+
+impl Default for TypeScale {
+    fn default() -> Self {
+        let styles = |prominent| {
+            VARIANTS.map(|variant| SIZES.map(|size| default_type_style(variant, size, prominent)))
+        };
+
+        Self {
+            regular: styles(false),
+            prominent: styles(true),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_precision_loss)]
+mod tests {
+    use freya::core::values::Fill;
+
+    use super::{
+        motion::Spring, CornerShape, Elevation, MorphShape, ShapeMorph, TypeScale, TypeStyle,
+        SIZES, VARIANTS,
+    };
+
+    const SQUARE: MorphShape = MorphShape::Corners(CornerShape::all(0.0));
+    const STAR: MorphShape = MorphShape::Star {
+        points: 5,
+        inner_radius: 0.5,
+        rounding: 0.0,
+    };
+
+    /// Integrates the spring numerically for comparison with `Spring::solve`.
+    fn simulate(spring: Spring, mut displacement: f32, mut velocity: f32, time: f32) -> (f32, f32) {
+        const STEPS: u32 = 100_000;
+
+        let step = time / STEPS as f32;
+
+        for _ in 0..STEPS {
+            let acceleration = (-spring.stiffness).mul_add(
+                displacement,
+                -2.0 * spring.damping * spring.stiffness.sqrt() * velocity,
+            );
+
+            velocity += acceleration * step;
+            displacement += velocity * step;
+        }
+
+        (displacement, velocity)
+    }
+
+    #[test]
+    fn solve_matches_simulation() {
+        for damping in [0.0, 0.6, 1.0, 1.5] {
+            let spring = Spring::new(damping, 380.0);
+
+            for (displacement, velocity) in [(1.0, 0.0), (-0.5, 20.0)] {
+                let (expected, expected_velocity) = simulate(spring, displacement, velocity, 0.1);
+                let (actual, actual_velocity) = spring.solve(displacement, velocity, 0.1);
+
+                assert!((expected - actual).abs() < 1e-2, "damping {damping}");
+                assert!(
+                    (expected_velocity - actual_velocity).abs() < 1e-1,
+                    "damping {damping}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn underdamped_spring_overshoots() {
+        let spring = Spring::new(0.6, 800.0);
+        let lowest = (1..200)
+            .map(|step| spring.solve(1.0, 0.0, step as f32 / 1000.0).0)
+            .fold(f32::INFINITY, f32::min);
+
+        assert!((lowest + spring.overshoot()).abs() < 1e-2);
+    }
+
+    #[test]
+    fn settling_duration_handles_degenerate_springs() {
+        assert_eq!(
+            Spring::new(0.9, 0.0).settling_duration(),
+            std::time::Duration::ZERO
+        );
+        assert_eq!(
+            Spring::new(0.9, -10.0).settling_duration(),
+            std::time::Duration::ZERO
+        );
+        assert_eq!(
+            Spring::new(0.0, 300.0).settling_duration(),
+            Spring::MAX_SETTLING_DURATION
+        );
+        assert_eq!(
+            Spring::new(-1.0, 300.0).settling_duration(),
+            Spring::MAX_SETTLING_DURATION
+        );
+        assert_eq!(
+            Spring::new(f32::NAN, 300.0).settling_duration(),
+            Spring::MAX_SETTLING_DURATION
+        );
+        assert!(
+            Spring::new(1.0, 1600.0).settling_duration() < std::time::Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn retarget_starts_from_current_outline() {
+        let morph = ShapeMorph::new(MorphShape::Circle, SQUARE, 0.25).retarget(STAR);
+
+        assert_eq!(morph.from, MorphShape::Circle);
+        assert_eq!(morph.via, Some((SQUARE, 0.25)));
+        assert_eq!(morph.to, STAR);
+        assert!(morph.progress.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn retarget_back_reverses_from_current_outline() {
+        let morph = ShapeMorph::new(MorphShape::Circle, SQUARE, 0.75).retarget(MorphShape::Circle);
+
+        assert_eq!(morph.from, SQUARE);
+        assert_eq!(morph.via, Some((MorphShape::Circle, 0.25)));
+        assert_eq!(morph.to, MorphShape::Circle);
+    }
+
+    #[test]
+    fn retarget_of_finished_morph_starts_at_target() {
+        let morph = ShapeMorph::fixed(SQUARE).retarget(STAR);
+
+        assert_eq!(morph.from, SQUARE);
+        assert_eq!(morph.via, None);
+    }
+
+    #[test]
+    fn default_shadow_alphas_match_the_previous_shadows() {
+        let alphas = Elevation::Level1
+            .as_shadows()
+            .map(|shadow| match shadow.fill {
+                Fill::Color(color) => color.a(),
+                _ => unreachable!(),
+            });
+
+        assert_eq!(alphas, [77, 38]);
+    }
+
+    #[test]
+    fn prominent_styles_are_heavier() {
+        let scale = TypeScale::default();
+
+        for variant in VARIANTS {
+            for size in SIZES {
+                let regular = scale.get(variant, size, false);
+                let prominent = scale.get(variant, size, true);
+
+                assert!(prominent.weight > regular.weight, "{variant:?} {size:?}");
+                assert_eq!(
+                    prominent,
+                    TypeStyle {
+                        weight: prominent.weight,
+                        ..regular
+                    }
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,160 @@
+use material_colors::{
+    blend::harmonize, color::Argb, hct::Hct, palette::TonalPalette, scheme::Scheme,
+};
+
+/// The four roles generated for a single accent color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorGroup {
+    pub color: Argb,
+    pub on_color: Argb,
+    pub color_container: Argb,
+    pub on_color_container: Argb,
+}
+
+impl ColorGroup {
+    #[must_use]
+    pub const fn primary(scheme: &Scheme) -> Self {
+        Self {
+            color: scheme.primary,
+            on_color: scheme.on_primary,
+            color_container: scheme.primary_container,
+            on_color_container: scheme.on_primary_container,
+        }
+    }
+
+    #[must_use]
+    pub const fn secondary(scheme: &Scheme) -> Self {
+        Self {
+            color: scheme.secondary,
+            on_color: scheme.on_secondary,
+            color_container: scheme.secondary_container,
+            on_color_container: scheme.on_secondary_container,
+        }
+    }
+
+    #[must_use]
+    pub const fn tertiary(scheme: &Scheme) -> Self {
+        Self {
+            color: scheme.tertiary,
+            on_color: scheme.on_tertiary,
+            color_container: scheme.tertiary_container,
+            on_color_container: scheme.on_tertiary_container,
+        }
+    }
+
+    #[must_use]
+    pub const fn error(scheme: &Scheme) -> Self {
+        Self {
+            color: scheme.error,
+            on_color: scheme.on_error,
+            color_container: scheme.error_container,
+            on_color_container: scheme.on_error_container,
+        }
+    }
+}
+
+/// A custom color (e.g. "success" or "warning") that lives next to the
+/// scheme roles.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExtendedColor {
+    pub name: String,
+    pub value: Argb,
+    /// Whether the hue of `value` is shifted towards the seed color, so it
+    /// fits better with the rest of the theme.
+    pub harmonize: bool,
+}
+
+impl ExtendedColor {
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: Argb, harmonize: bool) -> Self {
+        Self {
+            name: name.into(),
+            value,
+            harmonize,
+        }
+    }
+
+    // Same tones as `customColor` from https://github.com/material-foundation/material-color-utilities
+    #[must_use]
+    pub fn group(&self, seed: Argb, is_dark: bool) -> ColorGroup {
+        let value = if self.harmonize {
+            harmonize(self.value, seed)
+        } else {
+            self.value
+        };
+
+        let hct = Hct::new(value);
+        let palette = TonalPalette::of(hct.get_hue(), hct.get_chroma().max(48.0));
+
+        let [color, on_color, color_container, on_color_container] = if is_dark {
+            [80, 20, 30, 90]
+        } else {
+            [40, 100, 90, 10]
+        }
+        .map(|tone| palette.tone(tone));
+
+        ColorGroup {
+            color,
+            on_color,
+            color_container,
+            on_color_container,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: u32 = 0xFFB3261E;
+    const BLUE: u32 = 0xFF0061A4;
+
+    fn palette(value: Argb) -> TonalPalette {
+        let hct = Hct::new(value);
+
+        TonalPalette::of(hct.get_hue(), hct.get_chroma().max(48.0))
+    }
+
+    fn roles(group: ColorGroup) -> [Argb; 4] {
+        [
+            group.color,
+            group.on_color,
+            group.color_container,
+            group.on_color_container,
+        ]
+    }
+
+    #[test]
+    fn group_uses_custom_color_tones() {
+        let value = Argb::from_u32(RED);
+        let color = ExtendedColor::new("error", value, false);
+        let seed = Argb::from_u32(BLUE);
+        let palette = palette(value);
+
+        assert_eq!(
+            roles(color.group(seed, false)),
+            [40, 100, 90, 10].map(|tone| palette.tone(tone))
+        );
+        assert_eq!(
+            roles(color.group(seed, true)),
+            [80, 20, 30, 90].map(|tone| palette.tone(tone))
+        );
+    }
+
+    #[test]
+    fn harmonize_shifts_towards_seed() {
+        let value = Argb::from_u32(RED);
+        let seed = Argb::from_u32(BLUE);
+        let harmonized = ExtendedColor::new("error", value, true).group(seed, false);
+        let palette = palette(harmonize(value, seed));
+
+        assert_eq!(
+            roles(harmonized),
+            [40, 100, 90, 10].map(|tone| palette.tone(tone))
+        );
+        assert_ne!(
+            harmonized,
+            ExtendedColor::new("error", value, false).group(seed, false)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::{fmt, fs, io, path::Path};
+
+use freya::prelude::{Signal, Writable};
+use material_colors::{
+    color::Argb,
+    quantize::{Quantizer, QuantizerCelebi},
+    score::Score,
+};
+use skia_safe::{image::CachingHint, images, AlphaType, ColorType, Data, ImageInfo};
+
+use super::{MaterialTheme, SchemeVariant};
+
+const MAX_COLORS: usize = 128;
+const MAX_SAMPLES: usize = 128 * 128;
+
+#[derive(Debug)]
+pub enum ImageError {
+    Io(io::Error),
+    Decode,
+    NoColors,
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read image: {error}"),
+            Self::Decode => f.write_str("failed to decode image"),
+            Self::NoColors => f.write_str("image does not contain any opaque pixels"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Decode | Self::NoColors => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Returns seed color candidates found in `pixels`, ranked from the most to the
+/// least suitable one.
+#[must_use]
+pub fn seed_colors_from_pixels(pixels: &[Argb]) -> Vec<Argb> {
+    let step = (pixels.len() / MAX_SAMPLES).max(1);
+    let pixels = pixels
+        .iter()
+        .step_by(step)
+        .filter(|pixel| pixel.alpha == 255)
+        .copied()
+        .collect::<Vec<_>>();
+
+    if pixels.is_empty() {
+        return Vec::new();
+    }
+
+    let result = QuantizerCelebi::quantize(&pixels, MAX_COLORS);
+
+    Score::score(&result.color_to_count, None, None, None)
+}
+
+/// Decodes the image at `path` and returns its seed color candidates, ranked
+/// from the most to the least suitable one.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or decoded.
+pub fn seed_colors_from_image(path: impl AsRef<Path>) -> Result<Vec<Argb>, ImageError> {
+    let data = fs::read(path)?;
+    let image = images::deferred_from_encoded_data(Data::new_copy(&data), None)
+        .ok_or(ImageError::Decode)?;
+
+    let info = ImageInfo::new(
+        image.dimensions(),
+        ColorType::RGBA8888,
+        AlphaType::Unpremul,
+        None,
+    );
+    let row_bytes = info.min_row_bytes();
+    let mut bytes = vec![0u8; info.compute_min_byte_size()];
+
+    if !image.read_pixels(&info, &mut bytes, row_bytes, (0, 0), CachingHint::Disallow) {
+        return Err(ImageError::Decode);
+    }
+
+    let pixels = bytes
+        .chunks_exact(4)
+        .map(|pixel| Argb::new(pixel[3], pixel[0], pixel[1], pixel[2]))
+        .collect::<Vec<_>>();
+
+    Ok(seed_colors_from_pixels(&pixels))
+}
+
+/// Generates `theme` from the most suitable seed color of the image at `path`
+/// and returns all candidates, so one of them can be picked later with
+/// [`MaterialTheme::set_seed`].
+///
+/// `theme` is the signal returned by [`use_material_context`], so the image
+/// can be loaded from an event handler.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or decoded, or if it doesn't
+/// contain any opaque pixels.
+///
+/// [`use_material_context`]: super::use_material_context
+pub fn set_material_seed_from_image(
+    mut theme: Signal<MaterialTheme>,
+    path: impl AsRef<Path>,
+    variant: SchemeVariant,
+) -> Result<Vec<Argb>, ImageError> {
+    let colors = seed_colors_from_image(path)?;
+    let seed = *colors.first().ok_or(ImageError::NoColors)?;
+
+    theme.write().set_seed(seed, variant);
+
+    Ok(colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solid_color_is_the_top_seed() {
+        let color = Argb::from_u32(0xFF6750A4);
+        let pixels = vec![color; 256 * 256];
+
+        assert_eq!(seed_colors_from_pixels(&pixels).first(), Some(&color));
+    }
+
+    #[test]
+    fn transparent_pixels_are_ignored() {
+        let pixels = vec![Argb::from_u32(0x806750A4); 64];
+
+        assert!(seed_colors_from_pixels(&pixels).is_empty());
+    }
+}
@@ -0,0 +1,400 @@
+use std::{collections::BTreeMap, fmt};
+
+use freya::prelude::{Readable, Signal, Writable};
+use material_colors::{color::Argb, scheme::Scheme};
+use serde::{Deserialize, Serialize};
+
+use super::{Contrast, ExtendedColor, MaterialTheme, SchemeVariant};
+use crate::material_design::ColorRole;
+
+#[derive(Debug)]
+pub enum ThemeJsonError {
+    Json(serde_json::Error),
+    InvalidColor { key: String, value: String },
+    MissingScheme(String),
+}
+
+impl fmt::Display for ThemeJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "invalid theme file: {error}"),
+            Self::InvalidColor { key, value } => {
+                write!(f, "invalid color `{value}` for `{key}`, expected `#RRGGBB`")
+            }
+            Self::MissingScheme(name) => write!(f, "theme file has no `{name}` scheme"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeJsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            Self::InvalidColor { .. } | Self::MissingScheme(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ThemeJsonError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThemeFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    seed: String,
+    #[serde(default)]
+    core_colors: BTreeMap<String, String>,
+    #[serde(default)]
+    extended_colors: Vec<ExtendedColorEntry>,
+    schemes: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ExtendedColorEntry {
+    name: String,
+    color: String,
+    #[serde(default)]
+    harmonized: bool,
+}
+
+pub(super) fn parse_hex(value: &str) -> Option<Argb> {
+    value
+        .strip_prefix('#')
+        .filter(|hex| hex.len() == 6)
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .map(|rgb| Argb::from_u32(0xFF000000 | rgb))
+}
+
+fn parse_color(key: &str, value: &str) -> Result<Argb, ThemeJsonError> {
+    parse_hex(value).ok_or_else(|| ThemeJsonError::InvalidColor {
+        key: key.to_owned(),
+        value: value.to_owned(),
+    })
+}
+
+pub(super) fn format_color(color: Argb) -> String {
+    format!("#{:02X}{:02X}{:02X}", color.red, color.green, color.blue)
+}
+
+const LEVELS: [Contrast; 3] = [Contrast::Standard, Contrast::Medium, Contrast::High];
+
+pub(super) fn scheme_name(is_dark: bool, contrast: Contrast) -> String {
+    let base = if is_dark { "dark" } else { "light" };
+
+    match contrast.into_value() {
+        value if value >= 0.75 => format!("{base}-high-contrast"),
+        value if value >= 0.25 => format!("{base}-medium-contrast"),
+        _ => base.to_owned(),
+    }
+}
+
+fn read_scheme(
+    schemes: &BTreeMap<String, BTreeMap<String, String>>,
+    name: &str,
+    mut scheme: Scheme,
+) -> Result<Scheme, ThemeJsonError> {
+    let roles = schemes
+        .get(name)
+        .ok_or_else(|| ThemeJsonError::MissingScheme(name.to_owned()))?;
+
+    for (key, value) in roles {
+        if let Some(role) = ColorRole::from_name(key) {
+            *role.resolve_mut(&mut scheme) = parse_color(key, value)?;
+        }
+    }
+
+    Ok(scheme)
+}
+
+fn write_scheme(scheme: &Scheme) -> BTreeMap<String, String> {
+    ColorRole::ALL
+        .into_iter()
+        .map(|role| (role.name().to_owned(), format_color(role.resolve(scheme))))
+        .collect()
+}
+
+impl MaterialTheme {
+    /// Returns the imported light and dark schemes for the level nearest to
+    /// `contrast`, if the theme was loaded from a file that has both.
+    pub(super) fn imported_pair(&self, contrast: Contrast) -> Option<(Scheme, Scheme)> {
+        let light = self.imported_schemes.get(&scheme_name(false, contrast))?;
+        let dark = self.imported_schemes.get(&scheme_name(true, contrast))?;
+
+        Some((light.clone(), dark.clone()))
+    }
+
+    /// Parses a theme exported by the Material Theme Builder, using the
+    /// light and dark schemes that match `contrast`.
+    ///
+    /// Roles missing from the file are generated from its seed color.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file isn't valid JSON, contains a malformed
+    /// color, or lacks one of the required schemes.
+    pub fn from_theme_builder_json(json: &str, contrast: Contrast) -> Result<Self, ThemeJsonError> {
+        let file: ThemeFile = serde_json::from_str(json)?;
+
+        let mut theme = Self::from_seed(parse_color("seed", &file.seed)?, SchemeVariant::TonalSpot);
+
+        theme.contrast = contrast;
+        theme.import_file(file)?;
+
+        Ok(theme)
+    }
+
+    /// Replaces the seed color, schemes and extended colors with the ones
+    /// exported by the Material Theme Builder, keeping everything else.
+    ///
+    /// Every scheme in the file is kept, so switching the contrast level
+    /// later uses the imported schemes too. A [`Contrast::Custom`] level
+    /// shows the schemes of the nearest level in the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file isn't valid JSON, contains a malformed
+    /// color, or lacks one of the schemes for the current contrast level.
+    /// The theme is left unchanged on error.
+    pub fn import_theme_builder_json(&mut self, json: &str) -> Result<(), ThemeJsonError> {
+        self.import_file(serde_json::from_str(json)?)
+    }
+
+    fn import_file(&mut self, file: ThemeFile) -> Result<(), ThemeJsonError> {
+        let seed = parse_color("seed", &file.seed)?;
+
+        let mut imported_schemes = BTreeMap::new();
+
+        for contrast in LEVELS {
+            let (light, dark) = Self::generate(seed, self.variant, contrast);
+
+            for (is_dark, base) in [(false, light), (true, dark)] {
+                let name = scheme_name(is_dark, contrast);
+
+                if file.schemes.contains_key(&name) {
+                    let scheme = read_scheme(&file.schemes, &name, base)?;
+
+                    imported_schemes.insert(name, scheme);
+                }
+            }
+        }
+
+        let current = |is_dark| {
+            let name = scheme_name(is_dark, self.contrast);
+
+            imported_schemes
+                .get(&name)
+                .cloned()
+                .ok_or(ThemeJsonError::MissingScheme(name))
+        };
+        let (light, dark) = (current(false)?, current(true)?);
+
+        let extended_colors = file
+            .extended_colors
+            .into_iter()
+            .map(|color| {
+                let value = parse_color(&color.name, &color.color)?;
+
+                Ok(ExtendedColor::new(color.name, value, color.harmonized))
+            })
+            .collect::<Result<Vec<_>, ThemeJsonError>>()?;
+
+        self.seed = seed;
+        self.light = light;
+        self.dark = dark;
+        self.imported_schemes = imported_schemes;
+        self.extended_colors.clear();
+
+        for color in extended_colors {
+            self.add_extended_color(color);
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the theme in the Material Theme Builder format.
+    ///
+    /// The current light and dark schemes are exported under the nearest
+    /// contrast level, which is also how a [`Contrast::Custom`] level is
+    /// exported. The remaining levels use the imported schemes where
+    /// available and are generated from the seed color otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_theme_builder_json(&self) -> Result<String, ThemeJsonError> {
+        let mut schemes = BTreeMap::new();
+
+        for contrast in LEVELS {
+            let (light, dark) = if scheme_name(false, contrast) == scheme_name(false, self.contrast)
+            {
+                (self.light.clone(), self.dark.clone())
+            } else {
+                self.imported_pair(contrast)
+                    .unwrap_or_else(|| Self::generate(self.seed, self.variant, contrast))
+            };
+
+            schemes.insert(scheme_name(false, contrast), write_scheme(&light));
+            schemes.insert(scheme_name(true, contrast), write_scheme(&dark));
+        }
+
+        let file = ThemeFile {
+            description: None,
+            seed: format_color(self.seed),
+            core_colors: BTreeMap::from([("primary".to_owned(), format_color(self.seed))]),
+            extended_colors: self
+                .extended_colors
+                .iter()
+                .map(|color| ExtendedColorEntry {
+                    name: color.name.clone(),
+                    color: format_color(color.value),
+                    harmonized: color.harmonize,
+                })
+                .collect(),
+            schemes,
+        };
+
+        Ok(serde_json::to_string_pretty(&file)?)
+    }
+}
+
+/// Replaces the seed color, schemes and extended colors of `theme` with the
+/// ones exported by the Material Theme Builder. Everything else, such as the
+/// mode, contrast level, typefaces and motion scheme, is kept.
+///
+/// `theme` is the signal returned by [`use_material_context`], so the file
+/// can be loaded from an event handler.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be parsed, see
+/// [`MaterialTheme::import_theme_builder_json`]. The theme is left unchanged
+/// on error.
+///
+/// [`use_material_context`]: super::use_material_context
+pub fn load_material_theme_json(
+    mut theme: Signal<MaterialTheme>,
+    json: &str,
+) -> Result<(), ThemeJsonError> {
+    let mut imported = theme.peek().clone();
+
+    imported.import_theme_builder_json(json)?;
+    theme.set(imported);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::{material_design::motion::MotionScheme, theme::ThemeMode};
+
+    fn exported() -> Value {
+        let theme = MaterialTheme::from_seed(Argb::from_u32(0xFF6750A4), SchemeVariant::TonalSpot);
+
+        serde_json::from_str(&theme.to_theme_builder_json().unwrap()).unwrap()
+    }
+
+    fn import(json: &Value, contrast: Contrast) -> Result<MaterialTheme, ThemeJsonError> {
+        MaterialTheme::from_theme_builder_json(&json.to_string(), contrast)
+    }
+
+    #[test]
+    fn imported_schemes_round_trip() {
+        let mut json = exported();
+        json["schemes"]["dark-high-contrast"]["primary"] = "#123456".into();
+        json["schemes"]["light-medium-contrast"]["surface"] = "#FEDCBA".into();
+
+        let mut theme = import(&json, Contrast::Standard).unwrap();
+        assert_eq!(theme.imported_schemes.len(), 6);
+
+        let output: Value = serde_json::from_str(&theme.to_theme_builder_json().unwrap()).unwrap();
+        assert_eq!(output["schemes"], json["schemes"]);
+
+        theme.set_contrast(Contrast::High);
+        assert_eq!(theme.dark.primary, Argb::from_u32(0xFF123456));
+    }
+
+    #[test]
+    fn custom_contrast_exports_current_schemes() {
+        let mut theme = import(&exported(), Contrast::Standard).unwrap();
+        theme.set_contrast(Contrast::Custom(0.6));
+
+        let output: Value = serde_json::from_str(&theme.to_theme_builder_json().unwrap()).unwrap();
+        assert_eq!(
+            output["schemes"]["light-medium-contrast"]["primary"],
+            format_color(theme.light.primary)
+        );
+    }
+
+    #[test]
+    fn custom_contrast_uses_nearest_imported_schemes() {
+        let mut json = exported();
+        json["schemes"]["light-medium-contrast"]["primary"] = "#123456".into();
+
+        let mut theme = import(&json, Contrast::Custom(0.6)).unwrap();
+        assert_eq!(theme.light.primary, Argb::from_u32(0xFF123456));
+
+        theme.regenerate();
+        assert_eq!(theme.light.primary, Argb::from_u32(0xFF123456));
+
+        theme.set_contrast(Contrast::Custom(0.1));
+        assert_ne!(theme.light.primary, Argb::from_u32(0xFF123456));
+    }
+
+    #[test]
+    fn import_keeps_other_settings() {
+        let mut theme =
+            MaterialTheme::from_seed(Argb::from_u32(0xFF386A20), SchemeVariant::TonalSpot);
+        theme.mode = ThemeMode::Light;
+        theme.motion_scheme = MotionScheme::Expressive;
+
+        theme
+            .import_theme_builder_json(&exported().to_string())
+            .unwrap();
+
+        assert_eq!(theme.seed, Argb::from_u32(0xFF6750A4));
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert_eq!(theme.motion_scheme, MotionScheme::Expressive);
+    }
+
+    #[test]
+    fn invalid_color_is_rejected() {
+        let mut json = exported();
+        json["schemes"]["light"]["primary"] = "blue".into();
+
+        assert!(matches!(
+            import(&json, Contrast::Standard),
+            Err(ThemeJsonError::InvalidColor { key, value }) if key == "primary" && value == "blue"
+        ));
+    }
+
+    #[test]
+    fn missing_scheme_is_rejected() {
+        let mut json = exported();
+        json["schemes"]
+            .as_object_mut()
+            .unwrap()
+            .remove("dark-medium-contrast");
+
+        assert!(import(&json, Contrast::Standard).is_ok());
+        assert!(matches!(
+            import(&json, Contrast::Medium),
+            Err(ThemeJsonError::MissingScheme(name)) if name == "dark-medium-contrast"
+        ));
+    }
+
+    #[test]
+    fn malformed_json_is_rejected() {
+        assert!(matches!(
+            MaterialTheme::from_theme_builder_json("{", Contrast::Standard),
+            Err(ThemeJsonError::Json(_))
+        ));
+    }
+}
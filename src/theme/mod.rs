@@ -0,0 +1,413 @@
+use freya::prelude::{
+    try_consume_context, try_use_context, use_context_provider, use_effect, use_hook, use_memo,
+    use_preferred_theme, use_signal, Memo, PreferredTheme, Readable, Signal, Writable,
+};
+use freya_motion::use_transition;
+use material_colors::{
+    color::Argb,
+    dynamic_color::{DynamicScheme, Variant as SchemeVariant},
+    palette::TonalPalette,
+    scheme::Scheme,
+};
+use std::collections::BTreeMap;
+
+use crate::{
+    material_design::{
+        motion::{Duration, Easing, MotionScheme},
+        ColorRole, ShadowStyle, ShapeScale, TypeScale, Typefaces,
+    },
+    ArgbExt,
+};
+
+mod extended;
+mod image;
+mod json;
+mod persistence;
+
+pub use self::{
+    extended::{ColorGroup, ExtendedColor},
+    image::{
+        seed_colors_from_image, seed_colors_from_pixels, set_material_seed_from_image, ImageError,
+    },
+    json::{load_material_theme_json, ThemeJsonError},
+    persistence::{
+        use_persisted_material_theme, ThemePersistence, ThemeSettings, ThemeSettingsError,
+    },
+};
+
+/// Which scheme of a [`MaterialTheme`] is active. Defaults to `Dark`, matching
+/// the single dark scheme of earlier versions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ThemeMode {
+    Light,
+    #[default]
+    Dark,
+    /// Follows the preferred theme of the platform.
+    System,
+}
+
+impl ThemeMode {
+    #[must_use]
+    pub const fn is_dark(&self, preferred: PreferredTheme) -> bool {
+        match self {
+            Self::Light => false,
+            Self::Dark => true,
+            Self::System => matches!(preferred, PreferredTheme::Dark),
+        }
+    }
+}
+
+/// Contrast level of the generated schemes, ranging from `-1.0` (reduced) to
+/// `1.0` (high).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Contrast {
+    #[default]
+    Standard,
+    Medium,
+    High,
+    Custom(f64),
+}
+
+impl Contrast {
+    #[must_use]
+    pub fn as_value(&self) -> f64 {
+        match self {
+            Self::Standard => 0.0,
+            Self::Medium => 0.5,
+            Self::High => 1.0,
+            Self::Custom(value) => value.clamp(-1.0, 1.0),
+        }
+    }
+
+    #[must_use]
+    pub fn into_value(self) -> f64 {
+        self.as_value()
+    }
+
+    #[must_use]
+    pub fn from_value(value: f64) -> Self {
+        [Self::Standard, Self::Medium, Self::High]
+            .into_iter()
+            .find(|contrast| (contrast.as_value() - value).abs() < f64::EPSILON)
+            .unwrap_or(Self::Custom(value))
+    }
+}
+
+/// Tonal palettes the schemes of a [`MaterialTheme`] are generated from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialPalettes {
+    pub primary: TonalPalette,
+    pub secondary: TonalPalette,
+    pub tertiary: TonalPalette,
+    pub neutral: TonalPalette,
+    pub neutral_variant: TonalPalette,
+    pub error: TonalPalette,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialTheme {
+    pub seed: Argb,
+    pub variant: SchemeVariant,
+    pub contrast: Contrast,
+    pub light: Scheme,
+    pub dark: Scheme,
+    pub mode: ThemeMode,
+    pub extended_colors: Vec<ExtendedColor>,
+    /// Schemes imported with [`MaterialTheme::from_theme_builder_json`], keyed
+    /// by their Theme Builder name such as `dark-medium-contrast`. They are
+    /// used instead of generated schemes for their contrast level until the
+    /// seed color changes. A [`Contrast::Custom`] level uses the schemes of
+    /// the nearest level.
+    pub imported_schemes: BTreeMap<String, Scheme>,
+    pub typefaces: Typefaces,
+    pub type_scale: TypeScale,
+    pub shape_scale: ShapeScale,
+    pub shadow_style: ShadowStyle,
+    /// Motion used by the built-in components. Components read it when they
+    /// are mounted, so changing it only affects components mounted afterwards.
+    pub motion_scheme: MotionScheme,
+    /// Factor applied to the size and line height of every type style.
+    pub font_scale: f32,
+    /// Whether color roles are interpolated from the previous scheme when the
+    /// theme changes. Should be disabled in tests and for reduced motion.
+    pub animate: bool,
+}
+
+impl MaterialTheme {
+    #[must_use]
+    pub fn from_seed(seed: Argb, variant: SchemeVariant) -> Self {
+        let contrast = Contrast::default();
+        let (light, dark) = Self::generate(seed, variant, contrast);
+
+        Self {
+            seed,
+            variant,
+            contrast,
+            light,
+            dark,
+            mode: ThemeMode::default(),
+            extended_colors: Vec::new(),
+            imported_schemes: BTreeMap::new(),
+            typefaces: Typefaces::default(),
+            type_scale: TypeScale::default(),
+            shape_scale: ShapeScale::default(),
+            shadow_style: ShadowStyle::default(),
+            motion_scheme: MotionScheme::default(),
+            font_scale: 1.0,
+            animate: true,
+        }
+    }
+
+    pub(crate) fn generate(
+        seed: Argb,
+        variant: SchemeVariant,
+        contrast: Contrast,
+    ) -> (Scheme, Scheme) {
+        let scheme = |is_dark| {
+            Scheme::from(DynamicScheme::by_variant(
+                seed,
+                &variant,
+                is_dark,
+                Some(contrast.into_value()),
+            ))
+        };
+
+        (scheme(false), scheme(true))
+    }
+
+    pub(crate) fn regenerate(&mut self) {
+        (self.light, self.dark) = self
+            .imported_pair(self.contrast)
+            .unwrap_or_else(|| Self::generate(self.seed, self.variant, self.contrast));
+    }
+
+    /// Regenerates both schemes from a new seed color, keeping the current mode
+    /// and contrast level. Imported schemes are discarded.
+    pub fn set_seed(&mut self, seed: Argb, variant: SchemeVariant) {
+        self.seed = seed;
+        self.variant = variant;
+        self.imported_schemes.clear();
+        self.regenerate();
+    }
+
+    /// Regenerates both schemes from the current seed color with a new contrast
+    /// level.
+    ///
+    /// Schemes installed with [`set_material_theme`] are replaced as well.
+    pub fn set_contrast(&mut self, contrast: Contrast) {
+        self.contrast = contrast;
+        self.regenerate();
+    }
+
+    #[must_use]
+    pub fn palettes(&self) -> MaterialPalettes {
+        let scheme = DynamicScheme::by_variant(
+            self.seed,
+            &self.variant,
+            false,
+            Some(self.contrast.into_value()),
+        );
+
+        MaterialPalettes {
+            primary: scheme.primary_palette,
+            secondary: scheme.secondary_palette,
+            tertiary: scheme.tertiary_palette,
+            neutral: scheme.neutral_palette,
+            neutral_variant: scheme.neutral_variant_palette,
+            error: scheme.error_palette,
+        }
+    }
+
+    /// Sets the font scale, clamped between `0.5` and `3.0`.
+    pub fn set_font_scale(&mut self, font_scale: f32) {
+        self.font_scale = font_scale.clamp(0.5, 3.0);
+    }
+
+    /// Adds a custom color to the theme, replacing the one with the same name.
+    pub fn add_extended_color(&mut self, color: ExtendedColor) {
+        self.extended_colors
+            .retain(|value| value.name != color.name);
+        self.extended_colors.push(color);
+    }
+
+    /// Returns the roles of the custom color named `name`, generated for
+    /// either the light or the dark scheme.
+    #[must_use]
+    pub fn extended_color(&self, name: &str, is_dark: bool) -> Option<ColorGroup> {
+        self.extended_colors
+            .iter()
+            .find(|color| color.name == name)
+            .map(|color| color.group(self.seed, is_dark))
+    }
+
+    /// Returns the scheme matching the current mode, where `preferred` is
+    /// used to resolve [`ThemeMode::System`].
+    #[must_use]
+    pub const fn scheme(&self, preferred: PreferredTheme) -> &Scheme {
+        if self.mode.is_dark(preferred) {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+}
+
+impl Default for MaterialTheme {
+    fn default() -> Self {
+        Self::from_seed(Argb::from_u32(0xFFBC0D), SchemeVariant::TonalSpot)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct MaterialThemeContext {
+    pub(crate) theme: Signal<MaterialTheme>,
+    pub(crate) is_dark: Memo<bool>,
+    scheme: Memo<Scheme>,
+}
+
+fn lerp_scheme(from: &Scheme, to: &Scheme, progress: f32) -> Scheme {
+    let mut scheme = to.clone();
+
+    if progress < 1.0 {
+        for role in ColorRole::ALL {
+            *role.resolve_mut(&mut scheme) = role.resolve(from).lerp(role.resolve(to), progress);
+        }
+    }
+
+    scheme
+}
+
+pub(crate) fn use_provide_material_theme(
+    init: impl FnOnce() -> MaterialTheme,
+) -> MaterialThemeContext {
+    let theme = use_signal(init);
+    let preferred = use_preferred_theme();
+    let is_dark = use_memo(move || theme.read().mode.is_dark(*preferred.read()));
+    let target = use_memo(move || theme.read().scheme(*preferred.read()).clone());
+
+    let mut previous = use_signal(|| target.peek().clone());
+    let transition = use_transition(move |context| {
+        let motion = theme
+            .peek()
+            .motion_scheme
+            .motion(Easing::Standard, Duration::Medium2);
+
+        context.add_tween("progress", 1.0, motion.curve, motion.as_millis());
+    });
+
+    let scheme =
+        use_memo(move || lerp_scheme(&previous.read(), &target.read(), transition.get("progress")));
+
+    use_effect(move || {
+        target.read();
+
+        if theme.peek().animate {
+            previous.set(scheme.peek().clone());
+            transition.forced_set("progress", 0.0);
+            transition.play([("progress", 1.0)]);
+        } else {
+            transition.forced_set("progress", 1.0);
+        }
+    });
+
+    use_context_provider(|| MaterialThemeContext {
+        theme,
+        is_dark,
+        scheme,
+    })
+}
+
+fn use_material_theme_context() -> MaterialThemeContext {
+    try_use_context::<MaterialThemeContext>().map_or_else(
+        || use_provide_material_theme(MaterialTheme::default),
+        |value| value,
+    )
+}
+
+/// Returns the context of the nearest provider above the current component.
+/// It is looked up once, before a provider in the current component would
+/// shadow it.
+pub(crate) fn use_parent_material_context() -> Option<MaterialThemeContext> {
+    use_hook(try_consume_context::<MaterialThemeContext>)
+}
+
+/// Returns the theme context of the nearest `MaterialTheme` provider, which
+/// holds both light and dark schemes.
+#[must_use]
+pub fn use_material_context() -> Signal<MaterialTheme> {
+    use_material_theme_context().theme
+}
+
+/// Returns whether the dark scheme is currently active.
+#[must_use]
+pub fn use_material_is_dark() -> Memo<bool> {
+    use_material_theme_context().is_dark
+}
+
+/// Returns the tonal palettes of the current theme, e.g. for picking a tone
+/// that isn't part of the scheme.
+#[must_use]
+pub fn use_material_palettes() -> Memo<MaterialPalettes> {
+    let theme = use_material_context();
+
+    use_memo(move || theme.read().palettes())
+}
+
+/// Returns the scheme that is currently active according to the theme mode.
+#[must_use]
+pub fn use_material_theme() -> Memo<Scheme> {
+    use_material_theme_context().scheme
+}
+
+pub fn set_material_theme(light: Scheme, dark: Scheme) {
+    let mut theme = use_material_context();
+    let mut theme = theme.write();
+
+    theme.light = light;
+    theme.dark = dark;
+}
+
+/// Regenerates the light and dark schemes from `seed` using the given dynamic
+/// color algorithm.
+pub fn set_material_seed(seed: Argb, variant: SchemeVariant) {
+    use_material_context().write().set_seed(seed, variant);
+}
+
+pub fn set_material_contrast(contrast: Contrast) {
+    use_material_context().write().set_contrast(contrast);
+}
+
+/// Adds a custom color to the theme, replacing the one with the same name.
+pub fn add_extended_color(color: ExtendedColor) {
+    use_material_context().write().add_extended_color(color);
+}
+
+pub fn set_material_typefaces(typefaces: Typefaces) {
+    use_material_context().write().typefaces = typefaces;
+}
+
+pub fn set_material_type_scale(type_scale: TypeScale) {
+    use_material_context().write().type_scale = type_scale;
+}
+
+pub fn set_material_shape_scale(shape_scale: ShapeScale) {
+    use_material_context().write().shape_scale = shape_scale;
+}
+
+pub fn set_material_shadow_style(shadow_style: ShadowStyle) {
+    use_material_context().write().shadow_style = shadow_style;
+}
+
+/// Sets the motion scheme of the theme. Components that are already mounted
+/// keep the timings they were created with.
+pub fn set_material_motion_scheme(motion_scheme: MotionScheme) {
+    use_material_context().write().motion_scheme = motion_scheme;
+}
+
+pub fn set_material_font_scale(font_scale: f32) {
+    use_material_context().write().set_font_scale(font_scale);
+}
+
+pub fn set_theme_mode(mode: ThemeMode) {
+    use_material_context().write().mode = mode;
+}
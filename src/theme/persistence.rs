@@ -0,0 +1,388 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use freya::prelude::{
+    use_effect, use_future, use_hook, use_signal, EventHandler, Readable, Writable,
+};
+use material_colors::color::Argb;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    json::{format_color, parse_hex},
+    use_material_context, Contrast, MaterialTheme, SchemeVariant, ThemeMode,
+};
+
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+const VARIANTS: [SchemeVariant; 9] = [
+    SchemeVariant::Monochrome,
+    SchemeVariant::Neutral,
+    SchemeVariant::TonalSpot,
+    SchemeVariant::Vibrant,
+    SchemeVariant::Expressive,
+    SchemeVariant::Fidelity,
+    SchemeVariant::Content,
+    SchemeVariant::Rainbow,
+    SchemeVariant::FruitSalad,
+];
+
+const MODES: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+#[derive(Debug)]
+pub enum ThemeSettingsError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    InvalidValue { key: &'static str, value: String },
+}
+
+impl fmt::Display for ThemeSettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to access theme settings: {error}"),
+            Self::Json(error) => write!(f, "invalid theme settings: {error}"),
+            Self::InvalidValue { key, value } => write!(f, "invalid value `{value}` for `{key}`"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeSettingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Json(error) => Some(error),
+            Self::InvalidValue { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ThemeSettingsError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for ThemeSettingsError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+/// The part of [`MaterialTheme`] chosen by the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeSettings {
+    pub seed: Argb,
+    pub variant: SchemeVariant,
+    pub contrast: Contrast,
+    pub mode: ThemeMode,
+}
+
+impl ThemeSettings {
+    #[must_use]
+    pub const fn from_theme(theme: &MaterialTheme) -> Self {
+        Self {
+            seed: theme.seed,
+            variant: theme.variant,
+            contrast: theme.contrast,
+            mode: theme.mode,
+        }
+    }
+
+    /// Regenerates `theme` from these settings if they differ from it.
+    pub fn apply(self, theme: &mut MaterialTheme) {
+        if Self::from_theme(theme) == self {
+            return;
+        }
+
+        if (theme.seed, theme.variant) != (self.seed, self.variant) {
+            theme.imported_schemes.clear();
+        }
+
+        theme.seed = self.seed;
+        theme.variant = self.variant;
+        theme.contrast = self.contrast;
+        theme.mode = self.mode;
+        theme.regenerate();
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SettingsFile {
+    seed: String,
+    variant: String,
+    contrast: f64,
+    mode: String,
+}
+
+impl From<ThemeSettings> for SettingsFile {
+    fn from(settings: ThemeSettings) -> Self {
+        Self {
+            seed: format_color(settings.seed),
+            variant: format!("{:?}", settings.variant),
+            contrast: settings.contrast.into_value(),
+            mode: format!("{:?}", settings.mode),
+        }
+    }
+}
+
+impl TryFrom<SettingsFile> for ThemeSettings {
+    type Error = ThemeSettingsError;
+
+    fn try_from(file: SettingsFile) -> Result<Self, Self::Error> {
+        let invalid = |key, value: String| ThemeSettingsError::InvalidValue { key, value };
+
+        Ok(Self {
+            seed: parse_hex(&file.seed).ok_or_else(|| invalid("seed", file.seed.clone()))?,
+            variant: VARIANTS
+                .into_iter()
+                .find(|variant| format!("{variant:?}") == file.variant)
+                .ok_or_else(|| invalid("variant", file.variant.clone()))?,
+            contrast: Contrast::from_value(file.contrast),
+            mode: MODES
+                .into_iter()
+                .find(|mode| format!("{mode:?}") == file.mode)
+                .ok_or_else(|| invalid("mode", file.mode.clone()))?,
+        })
+    }
+}
+
+/// Location of the file the theme settings are stored in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ThemePersistence {
+    path: PathBuf,
+}
+
+impl ThemePersistence {
+    /// Stores the settings in `theme.json` inside the `app_name` folder of the
+    /// user config directory, or returns `None` if it can't be determined.
+    #[must_use]
+    pub fn new(app_name: &str) -> Option<Self> {
+        dirs::config_dir().map(|path| Self::with_path(path.join(app_name).join("theme.json")))
+    }
+
+    #[must_use]
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the stored settings, returning `None` if nothing was saved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or is malformed.
+    pub fn load(&self) -> Result<Option<ThemeSettings>, ThemeSettingsError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => {
+                let file: SettingsFile = serde_json::from_str(&content)?;
+
+                ThemeSettings::try_from(file).map(Some)
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the file or its parent directory can't be written.
+    pub fn save(&self, settings: ThemeSettings) -> Result<(), ThemeSettingsError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string_pretty(&SettingsFile::from(settings))?;
+
+        fs::write(&self.path, content)?;
+
+        Ok(())
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
+
+/// Restores the theme settings from `persistence` and saves them every time
+/// they change. With `watch`, the theme is also reloaded whenever the file is
+/// modified by someone else.
+///
+/// Failures to load or save are passed to `on_error`. After a failed load the
+/// settings are no longer saved, so a malformed file isn't overwritten, until
+/// the file can be loaded again.
+pub fn use_persisted_material_theme(
+    persistence: ThemePersistence,
+    watch: bool,
+    on_error: EventHandler<ThemeSettingsError>,
+) {
+    let mut context = use_material_context();
+    let persistence = use_hook(|| persistence);
+    let mut saved = use_signal(|| None::<ThemeSettings>);
+    let mut blocked = use_signal(|| false);
+
+    use_hook({
+        let persistence = persistence.clone();
+
+        move || match persistence.load() {
+            Ok(Some(settings)) => {
+                settings.apply(&mut context.write());
+                saved.set(Some(settings));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                blocked.set(true);
+                on_error.call(error);
+            }
+        }
+    });
+
+    use_effect({
+        let persistence = persistence.clone();
+
+        move || {
+            let settings = ThemeSettings::from_theme(&context.read());
+
+            if *blocked.read() || *saved.peek() == Some(settings) {
+                return;
+            }
+
+            match persistence.save(settings) {
+                Ok(()) => saved.set(Some(settings)),
+                Err(error) => on_error.call(error),
+            }
+        }
+    });
+
+    use_future(move || {
+        let persistence = persistence.clone();
+
+        async move {
+            if !watch {
+                return;
+            }
+
+            let mut last_modified = persistence.modified();
+
+            loop {
+                tokio::time::sleep(WATCH_INTERVAL).await;
+
+                let modified = persistence.modified();
+
+                if modified == last_modified {
+                    continue;
+                }
+
+                last_modified = modified;
+
+                match persistence.load() {
+                    Ok(Some(settings)) => {
+                        if *saved.peek() != Some(settings) {
+                            saved.set(Some(settings));
+                            settings.apply(&mut context.write());
+                        }
+
+                        blocked.set(false);
+                    }
+                    Ok(None) => blocked.set(false),
+                    Err(error) => {
+                        blocked.set(true);
+                        on_error.call(error);
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> ThemeSettings {
+        ThemeSettings {
+            seed: Argb::from_u32(0xFF6750A4),
+            variant: SchemeVariant::Vibrant,
+            contrast: Contrast::Medium,
+            mode: ThemeMode::Light,
+        }
+    }
+
+    fn file(variant: &str, mode: &str) -> SettingsFile {
+        SettingsFile {
+            seed: "#6750A4".into(),
+            variant: variant.into(),
+            contrast: 0.0,
+            mode: mode.into(),
+        }
+    }
+
+    #[test]
+    fn settings_round_trip_through_file() {
+        let file = SettingsFile::from(settings());
+
+        assert_eq!(file.seed, "#6750A4");
+        assert_eq!(file.variant, "Vibrant");
+        assert_eq!(file.mode, "Light");
+        assert_eq!(ThemeSettings::try_from(file).unwrap(), settings());
+    }
+
+    #[test]
+    fn contrast_is_normalized() {
+        assert_eq!(Contrast::from_value(0.0), Contrast::Standard);
+        assert_eq!(Contrast::from_value(0.5), Contrast::Medium);
+        assert_eq!(Contrast::from_value(1.0), Contrast::High);
+        assert_eq!(Contrast::from_value(0.25), Contrast::Custom(0.25));
+    }
+
+    #[test]
+    fn unknown_variant_is_rejected() {
+        let error = ThemeSettings::try_from(file("Pastel", "Dark")).unwrap_err();
+
+        assert!(matches!(
+            error,
+            ThemeSettingsError::InvalidValue { key: "variant", value } if value == "Pastel"
+        ));
+    }
+
+    #[test]
+    fn unknown_mode_is_rejected() {
+        let error = ThemeSettings::try_from(file("TonalSpot", "Dusk")).unwrap_err();
+
+        assert!(matches!(
+            error,
+            ThemeSettingsError::InvalidValue { key: "mode", value } if value == "Dusk"
+        ));
+    }
+
+    #[test]
+    fn missing_file_loads_nothing() {
+        let persistence = ThemePersistence::with_path(
+            std::env::temp_dir().join("freya-material-missing/theme.json"),
+        );
+
+        assert!(persistence.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn saved_settings_load_back() {
+        let persistence = ThemePersistence::with_path(
+            std::env::temp_dir()
+                .join(format!("freya-material-{}", std::process::id()))
+                .join("theme.json"),
+        );
+
+        persistence.save(settings()).unwrap();
+
+        assert_eq!(persistence.load().unwrap(), Some(settings()));
+
+        fs::remove_dir_all(persistence.path().parent().unwrap()).unwrap();
+    }
+}